@@ -0,0 +1,45 @@
+use discord_rs_core::traits::FileUpload;
+
+/// A file to upload with a message, see `MessageBuilder::add_attachment`.
+#[derive(Debug, Clone)]
+pub struct CreateAttachment {
+    file: FileUpload,
+    description: Option<String>,
+    spoiler: bool,
+}
+
+impl CreateAttachment {
+    pub fn new(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            file: FileUpload::new(filename, data),
+            description: None,
+            spoiler: false,
+        }
+    }
+
+    /// Alt text shown for the attachment.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Hides the attachment behind a spoiler (Discord keys this off a
+    /// `SPOILER_` filename prefix).
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.file = self.file.content_type(content_type);
+        self
+    }
+
+    pub(crate) fn into_parts(self) -> (FileUpload, Option<String>) {
+        let mut file = self.file;
+        if self.spoiler && !file.filename.starts_with("SPOILER_") {
+            file.filename = format!("SPOILER_{}", file.filename);
+        }
+        (file, self.description)
+    }
+}
@@ -1,14 +1,19 @@
+use discord_rs_core::traits::FileUpload;
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use discord_rs_model::attachment::PartialAttachment;
 use discord_rs_model::interaction::{InteractionResponse, InteractionResponseType, InteractionResponseData};
 use discord_rs_model::embed::Embed;
+use crate::attachment::CreateAttachment;
 
 pub struct InteractionResponseBuilder {
     kind: InteractionResponseType,
     data: Option<InteractionResponseData>,
+    files: Vec<FileUpload>,
 }
 
 impl InteractionResponseBuilder {
     pub fn new(kind: InteractionResponseType) -> Self {
-        Self { kind, data: None }
+        Self { kind, data: None, files: Vec::new() }
     }
 
     pub fn reply(content: impl Into<String>) -> Self {
@@ -21,66 +26,74 @@ impl InteractionResponseBuilder {
         Self::new(InteractionResponseType::DeferredChannelMessageWithSource)
     }
 
+    fn data(&mut self) -> &mut InteractionResponseData {
+        self.data.get_or_insert_with(InteractionResponseData::default)
+    }
+
     pub fn content(&mut self, content: impl Into<String>) -> &mut Self {
-        let data = self.data.get_or_insert_with(|| InteractionResponseData {
-            tts: None,
-            content: None,
-            embeds: None,
-            allowed_mentions: None,
-            flags: None,
-            components: None,
-            choices: None,
-            custom_id: None,
-            title: None,
-        });
-        data.content = Some(content.into());
+        self.data().content = Some(content.into());
         self
     }
 
     pub fn add_embed(&mut self, embed: impl Into<Embed>) -> &mut Self {
-        let data = self.data.get_or_insert_with(|| InteractionResponseData {
-            tts: None,
-            content: None,
-            embeds: None,
-            allowed_mentions: None,
-            flags: None,
-            components: None,
-            choices: None,
-            custom_id: None,
-            title: None,
-        });
-        let embeds = data.embeds.get_or_insert_with(Vec::new);
+        let embeds = self.data().embeds.get_or_insert_with(Vec::new);
         embeds.push(embed.into());
         self
     }
 
     pub fn ephemeral(&mut self) -> &mut Self {
-        let data = self.data.get_or_insert_with(|| InteractionResponseData {
-            tts: None,
-            content: None,
-            embeds: None,
-            allowed_mentions: None,
-            flags: None,
-            components: None,
-            choices: None,
-            custom_id: None,
-            title: None,
-        });
-        let flags = data.flags.get_or_insert(0);
+        let flags = self.data().flags.get_or_insert(0);
         *flags |= 1 << 6; // EPHEMERAL
         self
     }
 
-    pub fn build(self) -> InteractionResponse {
-        InteractionResponse {
+    pub fn add_file(&mut self, filename: impl Into<String>, data: impl Into<Vec<u8>>) -> &mut Self {
+        self.add_attachment(CreateAttachment::new(filename, data))
+    }
+
+    pub fn add_attachment(&mut self, attachment: CreateAttachment) -> &mut Self {
+        let (file, description) = attachment.into_parts();
+        let id = Snowflake::new(self.files.len() as u64);
+        let attachments = self.data().attachments.get_or_insert_with(Vec::new);
+        attachments.push(PartialAttachment {
+            id,
+            filename: Some(file.filename.clone()),
+            description,
+        });
+        self.files.push(file);
+        self
+    }
+
+    /// The response. Fails when files were added, since they can't be sent
+    /// with it; use `into_parts` for those.
+    pub fn build(self) -> Result<InteractionResponse> {
+        if !self.files.is_empty() {
+            return Err(DiscordError::Validation(
+                "interaction response has files; send it with into_parts as multipart".to_string(),
+            ));
+        }
+        Ok(InteractionResponse {
             kind: self.kind,
             data: self.data,
-        }
+        })
+    }
+
+    /// JSON payload and files to upload alongside it.
+    pub fn into_parts(mut self) -> Result<(serde_json::Value, Vec<FileUpload>)> {
+        let files = std::mem::take(&mut self.files);
+        let response = InteractionResponse {
+            kind: self.kind,
+            data: self.data,
+        };
+        let payload = serde_json::to_value(response).map_err(|e| DiscordError::Serialization(e.to_string()))?;
+        Ok((payload, files))
     }
 }
 
-impl From<InteractionResponseBuilder> for InteractionResponse {
-    fn from(builder: InteractionResponseBuilder) -> Self {
+impl TryFrom<InteractionResponseBuilder> for InteractionResponse {
+    type Error = DiscordError;
+
+    fn try_from(builder: InteractionResponseBuilder) -> Result<Self> {
         builder.build()
     }
 }
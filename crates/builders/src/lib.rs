@@ -2,16 +2,24 @@ pub mod embed;
 pub mod message;
 pub mod interaction;
 pub mod component;
+pub mod attachment;
 
 pub use embed::EmbedBuilder;
 pub use message::MessageBuilder;
 pub use interaction::InteractionResponseBuilder;
 pub use component::{ActionRowBuilder, ButtonBuilder, SelectMenuBuilder};
+pub use attachment::CreateAttachment;
 
 use discord_rs_core::{Context, Snowflake, Result, DiscordError};
+use discord_rs_core::traits::{FileUpload, HttpExt, HttpRequest};
 use discord_rs_model::{Message, Interaction};
 use async_trait::async_trait;
 
+/// JSON, or multipart when the builder carried files.
+fn message_request(request: HttpRequest, (body, files): (serde_json::Value, Vec<FileUpload>)) -> HttpRequest {
+    request.body(body).files(files)
+}
+
 #[async_trait]
 pub trait MessageSend {
     async fn send(&self, ctx: &Context, builder: MessageBuilder) -> Result<Box<Message>>;
@@ -20,12 +28,8 @@ pub trait MessageSend {
 #[async_trait]
 impl MessageSend for Snowflake {
     async fn send(&self, ctx: &Context, builder: MessageBuilder) -> Result<Box<Message>> {
-        let path = format!("/channels/{}/messages", self);
-        let body = builder.build();
-        let value = ctx.http.post(&path, body).await?;
-        let msg: Message = serde_json::from_value(value)
-            .map_err(|e| DiscordError::Serialization(e.to_string()))?;
-        Ok(Box::new(msg))
+        let request = message_request(HttpRequest::post(format!("/channels/{}/messages", self)), builder.into_parts()?);
+        ctx.http.send_as(request).await
     }
 }
 
@@ -54,11 +58,11 @@ pub trait InteractionReply {
 #[async_trait]
 impl InteractionReply for Interaction {
     async fn reply(&self, ctx: &Context, builder: InteractionResponseBuilder) -> Result<()> {
-        let path = format!("/interactions/{}/{}/callback", self.id, self.token);
-        let body = serde_json::to_value(builder.build())
-            .map_err(|e| DiscordError::Serialization(e.to_string()))?;
-        ctx.http.post(&path, body).await?;
-        Ok(())
+        let request = message_request(
+            HttpRequest::post(format!("/interactions/{}/{}/callback", self.id, self.token)),
+            builder.into_parts()?,
+        );
+        ctx.http.send_empty(request).await
     }
 
     async fn defer(&self, ctx: &Context) -> Result<()> {
@@ -68,28 +72,18 @@ impl InteractionReply for Interaction {
 
     async fn follow_up(&self, ctx: &Context, builder: MessageBuilder) -> Result<Box<Message>> {
         let app_id = ctx.config.application_id.ok_or(DiscordError::Configuration("application_id required for follow-up".to_string()))?;
-        let path = format!("/webhooks/{}/{}/messages", app_id, self.token);
-        let body = builder.build();
-        let value = ctx.http.post(&path, body).await?;
-        let msg: Message = serde_json::from_value(value)
-            .map_err(|e| DiscordError::Serialization(e.to_string()))?;
-        Ok(Box::new(msg))
+        let request = message_request(HttpRequest::post(format!("/webhooks/{}/{}/messages", app_id, self.token)), builder.into_parts()?);
+        ctx.http.send_as(request).await
     }
 
     async fn edit_reply(&self, ctx: &Context, builder: MessageBuilder) -> Result<Box<Message>> {
         let app_id = ctx.config.application_id.ok_or(DiscordError::Configuration("application_id required for editing reply".to_string()))?;
-        let path = format!("/webhooks/{}/{}/messages/@original", app_id, self.token);
-        let body = builder.build();
-        let value = ctx.http.patch(&path, body).await?;
-        let msg: Message = serde_json::from_value(value)
-            .map_err(|e| DiscordError::Serialization(e.to_string()))?;
-        Ok(Box::new(msg))
+        let request = message_request(HttpRequest::patch(format!("/webhooks/{}/{}/messages/@original", app_id, self.token)), builder.into_parts()?);
+        ctx.http.send_as(request).await
     }
 
     async fn delete_reply(&self, ctx: &Context) -> Result<()> {
         let app_id = ctx.config.application_id.ok_or(DiscordError::Configuration("application_id required for deleting reply".to_string()))?;
-        let path = format!("/webhooks/{}/{}/messages/@original", app_id, self.token);
-        ctx.http.delete(&path).await?;
-        Ok(())
+        ctx.http.send_empty(HttpRequest::delete(format!("/webhooks/{}/{}/messages/@original", app_id, self.token))).await
     }
 }
@@ -1,6 +1,10 @@
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use discord_rs_core::traits::FileUpload;
+use discord_rs_model::attachment::PartialAttachment;
 use discord_rs_model::message::{AllowedMentions, MessageReference};
 use discord_rs_model::embed::Embed;
 use serde::Serialize;
+use crate::attachment::CreateAttachment;
 
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct MessageBuilder {
@@ -14,6 +18,11 @@ pub struct MessageBuilder {
     pub message_reference: Option<MessageReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<PartialAttachment>>,
+    /// Uploaded as `files[n]`; sending switches to multipart when non-empty.
+    #[serde(skip)]
+    pub files: Vec<FileUpload>,
 }
 
 impl MessageBuilder {
@@ -65,9 +74,65 @@ impl MessageBuilder {
         self
     }
 
+    pub fn add_file(self, filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        self.add_attachment(CreateAttachment::new(filename, data))
+    }
+
+    pub fn add_attachment(mut self, attachment: CreateAttachment) -> Self {
+        let (file, description) = attachment.into_parts();
+        let attachments = self.attachments.get_or_insert_with(Vec::new);
+        attachments.push(PartialAttachment {
+            id: Snowflake::new(self.files.len() as u64),
+            filename: Some(file.filename.clone()),
+            description,
+        });
+        self.files.push(file);
+        self
+    }
+
+    /// Keeps an existing attachment when editing. Once any attachment is
+    /// listed, unlisted existing ones are removed by Discord.
+    pub fn keep_attachment(mut self, id: Snowflake) -> Self {
+        let attachments = self.attachments.get_or_insert_with(Vec::new);
+        attachments.push(PartialAttachment {
+            id,
+            filename: None,
+            description: None,
+        });
+        self
+    }
+
+    /// Removes all existing attachments when editing.
+    pub fn clear_attachments(mut self) -> Self {
+        self.attachments = Some(Vec::new());
+        self.files.clear();
+        self
+    }
+
+    /// The JSON payload. Added files are listed in `attachments` but their
+    /// contents aren't part of it; use `try_build` or `into_parts` when the
+    /// message may carry files.
     pub fn build(self) -> serde_json::Value {
         serde_json::to_value(self).unwrap_or(serde_json::json!({}))
     }
+
+    /// Like `build`, but fails when files were added instead of leaving them
+    /// behind.
+    pub fn try_build(self) -> Result<serde_json::Value> {
+        if !self.files.is_empty() {
+            return Err(DiscordError::Validation(
+                "message has files; send it with into_parts as multipart".to_string(),
+            ));
+        }
+        Ok(self.into_parts()?.0)
+    }
+
+    /// JSON payload and files to upload alongside it.
+    pub fn into_parts(mut self) -> Result<(serde_json::Value, Vec<FileUpload>)> {
+        let files = std::mem::take(&mut self.files);
+        let payload = serde_json::to_value(self).map_err(|e| DiscordError::Serialization(e.to_string()))?;
+        Ok((payload, files))
+    }
 }
 
 impl From<MessageBuilder> for serde_json::Value {
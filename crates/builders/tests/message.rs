@@ -0,0 +1,74 @@
+use discord_rs_builders::{CreateAttachment, InteractionResponseBuilder, MessageBuilder};
+use discord_rs_core::DiscordError;
+use discord_rs_core::Snowflake;
+use serde_json::json;
+
+#[test]
+fn test_plain_message_has_no_attachments() {
+    let (payload, files) = MessageBuilder::new().content("hi").into_parts().unwrap();
+    assert_eq!(payload, json!({ "content": "hi" }));
+    assert!(files.is_empty());
+}
+
+#[test]
+fn test_files_get_indexed_attachment_entries() {
+    let (payload, files) = MessageBuilder::new()
+        .add_file("a.txt", b"a".to_vec())
+        .add_attachment(
+            CreateAttachment::new("cat.png", vec![1, 2, 3])
+                .description("a cat")
+                .spoiler(true),
+        )
+        .into_parts().unwrap();
+
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[1].filename, "SPOILER_cat.png");
+    assert_eq!(
+        payload["attachments"],
+        json!([
+            { "id": "0", "filename": "a.txt" },
+            { "id": "1", "filename": "SPOILER_cat.png", "description": "a cat" },
+        ])
+    );
+}
+
+#[test]
+fn test_edit_can_keep_existing_attachments() {
+    let (payload, files) = MessageBuilder::new()
+        .keep_attachment(Snowflake::new(123))
+        .into_parts().unwrap();
+
+    assert!(files.is_empty());
+    assert_eq!(payload["attachments"], json!([{ "id": "123" }]));
+}
+
+#[test]
+fn test_try_build_rejects_files() {
+    assert_eq!(MessageBuilder::new().content("hi").build(), json!({ "content": "hi" }));
+    assert_eq!(MessageBuilder::new().content("hi").try_build().unwrap(), json!({ "content": "hi" }));
+
+    let err = MessageBuilder::new().add_file("a.txt", b"a".to_vec()).try_build().unwrap_err();
+    assert!(matches!(err, DiscordError::Validation(_)));
+}
+
+#[test]
+fn test_interaction_response_carries_files() {
+    let mut builder = InteractionResponseBuilder::reply("here");
+    builder.add_file("a.txt", b"a".to_vec());
+    let (payload, files) = builder.into_parts().unwrap();
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(payload["type"], 4);
+    assert_eq!(payload["data"]["content"], "here");
+    assert_eq!(payload["data"]["attachments"], json!([{ "id": "0", "filename": "a.txt" }]));
+}
+
+#[test]
+fn test_interaction_build_rejects_files() {
+    let response = InteractionResponseBuilder::reply("here").build().unwrap();
+    assert_eq!(response.data.unwrap().content.as_deref(), Some("here"));
+
+    let mut builder = InteractionResponseBuilder::reply("here");
+    builder.add_file("a.txt", b"a".to_vec());
+    assert!(matches!(builder.build(), Err(DiscordError::Validation(_))));
+}
@@ -1,5 +1,5 @@
 use dashmap::DashMap;
-use discord_rs_model::{User, Guild, Channel, Role, Member, Snowflake};
+use discord_rs_model::{User, Guild, Channel, Member, Role, Snowflake};
 use std::sync::Arc;
 
 #[derive(Debug, Default)]
@@ -39,8 +39,53 @@ impl Cache {
     pub fn update_member(&self, guild_id: Snowflake, member: Member) -> Arc<Member> {
         let user_id = member.user.as_ref().map(|u| u.id).expect("Member must have user for caching");
         let arc = Arc::new(member);
-        let guild_members = self.members.entry(guild_id).or_insert_with(DashMap::new);
+        let guild_members = self.members.entry(guild_id).or_default();
         guild_members.insert(user_id, arc.clone());
         arc
     }
+
+    pub fn remove_member(&self, guild_id: Snowflake, user_id: Snowflake) -> Option<Arc<Member>> {
+        self.members.get(&guild_id)
+            .and_then(|g| g.remove(&user_id).map(|(_, m)| m))
+    }
+
+    /// Adds a role to a cached member. No-op if the member isn't cached.
+    pub fn add_member_role(&self, guild_id: Snowflake, user_id: Snowflake, role_id: Snowflake) {
+        self.edit_member(guild_id, user_id, |member| {
+            if !member.roles.contains(&role_id) {
+                member.roles.push(role_id);
+            }
+        });
+    }
+
+    /// Removes a role from a cached member. No-op if the member isn't cached.
+    pub fn remove_member_role(&self, guild_id: Snowflake, user_id: Snowflake, role_id: Snowflake) {
+        self.edit_member(guild_id, user_id, |member| member.roles.retain(|id| *id != role_id));
+    }
+
+    fn edit_member(&self, guild_id: Snowflake, user_id: Snowflake, edit: impl FnOnce(&mut Member)) {
+        if let Some(guild_members) = self.members.get(&guild_id) {
+            if let Some(mut member) = guild_members.get_mut(&user_id) {
+                edit(Arc::make_mut(member.value_mut()));
+            }
+        }
+    }
+
+    /// Inserts or replaces a role of a cached guild. No-op if the guild isn't cached.
+    pub fn update_role(&self, guild_id: Snowflake, role: Role) {
+        if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+            let roles = &mut Arc::make_mut(guild.value_mut()).roles;
+            match roles.iter_mut().find(|r| r.id == role.id) {
+                Some(existing) => *existing = role,
+                None => roles.push(role),
+            }
+        }
+    }
+
+    pub fn remove_role(&self, guild_id: Snowflake, role_id: Snowflake) -> Option<Role> {
+        let mut guild = self.guilds.get_mut(&guild_id)?;
+        let roles = &mut Arc::make_mut(guild.value_mut()).roles;
+        let index = roles.iter().position(|r| r.id == role_id)?;
+        Some(roles.remove(index))
+    }
 }
@@ -6,7 +6,7 @@ pub use managers::*;
 
 use discord_rs_model::event::Event;
 use std::sync::Arc;
-use discord_rs_core::Context;
+use discord_rs_core::{Context, Snowflake};
 
 pub fn update_cache_from_event(cache: &Arc<Cache>, event: &Event) {
     match event {
@@ -33,6 +33,8 @@ pub trait ContextCacheExt {
     fn users(&self) -> UserManager;
     fn guilds(&self) -> GuildManager;
     fn channels(&self) -> ChannelManager;
+    fn members(&self, guild_id: Snowflake) -> GuildMemberManager;
+    fn roles(&self, guild_id: Snowflake) -> GuildRoleManager;
 }
 
 impl ContextCacheExt for Context {
@@ -51,4 +53,12 @@ impl ContextCacheExt for Context {
     fn channels(&self) -> ChannelManager {
         ChannelManager::new(self.cache(), self.http.clone())
     }
+
+    fn members(&self, guild_id: Snowflake) -> GuildMemberManager {
+        GuildMemberManager::new(guild_id, self.cache(), self.http.clone())
+    }
+
+    fn roles(&self, guild_id: Snowflake) -> GuildRoleManager {
+        GuildRoleManager::new(guild_id, self.cache(), self.http.clone())
+    }
 }
@@ -1,7 +1,19 @@
 use std::sync::Arc;
-use discord_rs_core::{Result, Snowflake, DiscordError, traits::Http};
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use discord_rs_core::traits::{Http, HttpExt, HttpRequest};
 use crate::cache::Cache;
-use discord_rs_model::{User, Guild, Channel, Member};
+use discord_rs_model::{User, Guild, Channel, Member, Role, Ban};
+use discord_rs_model::guild::BulkBanResponse;
+use discord_rs_model::member::ModifyGuildMember;
+use discord_rs_model::role::{EditRole, ModifyRolePosition};
+
+/// Attaches an audit log reason, if any.
+fn with_reason(request: HttpRequest, reason: Option<&str>) -> HttpRequest {
+    match reason {
+        Some(reason) => request.reason(reason),
+        None => request,
+    }
+}
 
 pub struct UserManager {
     cache: Arc<Cache>,
@@ -18,9 +30,7 @@ impl UserManager {
     }
 
     pub async fn fetch(&self, id: Snowflake) -> Result<Arc<User>> {
-        let value = self.http.get(&format!("/users/{}", id)).await?;
-        let user: User = serde_json::from_value(value)
-            .map_err(|e| DiscordError::Serialization(e.to_string()))?;
+        let user: User = self.http.send_as(HttpRequest::get(format!("/users/{}", id))).await?;
         Ok(self.cache.update_user(user))
     }
 }
@@ -40,9 +50,7 @@ impl GuildManager {
     }
 
     pub async fn fetch(&self, id: Snowflake) -> Result<Arc<Guild>> {
-        let value = self.http.get(&format!("/guilds/{}", id)).await?;
-        let guild: Guild = serde_json::from_value(value)
-            .map_err(|e| DiscordError::Serialization(e.to_string()))?;
+        let guild: Guild = self.http.send_as(HttpRequest::get(format!("/guilds/{}", id))).await?;
         Ok(self.cache.update_guild(guild))
     }
 }
@@ -62,9 +70,7 @@ impl ChannelManager {
     }
 
     pub async fn fetch(&self, id: Snowflake) -> Result<Arc<Channel>> {
-        let value = self.http.get(&format!("/channels/{}", id)).await?;
-        let channel: Channel = serde_json::from_value(value)
-            .map_err(|e| DiscordError::Serialization(e.to_string()))?;
+        let channel: Channel = self.http.send_as(HttpRequest::get(format!("/channels/{}", id))).await?;
         Ok(self.cache.update_channel(channel))
     }
 }
@@ -86,9 +92,126 @@ impl GuildMemberManager {
     }
 
     pub async fn fetch(&self, user_id: Snowflake) -> Result<Arc<Member>> {
-        let value = self.http.get(&format!("/guilds/{}/members/{}", self.guild_id, user_id)).await?;
-        let member: Member = serde_json::from_value(value)
-            .map_err(|e| DiscordError::Serialization(e.to_string()))?;
+        let member: Member = self.http.send_as(HttpRequest::get(format!("/guilds/{}/members/{}", self.guild_id, user_id))).await?;
+        Ok(self.cache.update_member(self.guild_id, member))
+    }
+
+    pub async fn edit(&self, user_id: Snowflake, member: ModifyGuildMember, reason: Option<&str>) -> Result<Arc<Member>> {
+        let request = HttpRequest::patch(format!("/guilds/{}/members/{}", self.guild_id, user_id)).json(&member)?;
+        let member: Member = self.http.send_as(with_reason(request, reason)).await?;
         Ok(self.cache.update_member(self.guild_id, member))
     }
-}
\ No newline at end of file
+
+    pub async fn add_role(&self, user_id: Snowflake, role_id: Snowflake, reason: Option<&str>) -> Result<()> {
+        let request = HttpRequest::put(format!("/guilds/{}/members/{}/roles/{}", self.guild_id, user_id, role_id));
+        self.http.send_empty(with_reason(request, reason)).await?;
+        self.cache.add_member_role(self.guild_id, user_id, role_id);
+        Ok(())
+    }
+
+    pub async fn remove_role(&self, user_id: Snowflake, role_id: Snowflake, reason: Option<&str>) -> Result<()> {
+        let request = HttpRequest::delete(format!("/guilds/{}/members/{}/roles/{}", self.guild_id, user_id, role_id));
+        self.http.send_empty(with_reason(request, reason)).await?;
+        self.cache.remove_member_role(self.guild_id, user_id, role_id);
+        Ok(())
+    }
+
+    pub async fn kick(&self, user_id: Snowflake, reason: Option<&str>) -> Result<()> {
+        let request = HttpRequest::delete(format!("/guilds/{}/members/{}", self.guild_id, user_id));
+        self.http.send_empty(with_reason(request, reason)).await?;
+        self.cache.remove_member(self.guild_id, user_id);
+        Ok(())
+    }
+
+    pub async fn ban(&self, user_id: Snowflake, delete_message_seconds: Option<u32>, reason: Option<&str>) -> Result<()> {
+        let mut request = HttpRequest::put(format!("/guilds/{}/bans/{}", self.guild_id, user_id));
+        if let Some(secs) = delete_message_seconds {
+            request = request.body(serde_json::json!({ "delete_message_seconds": secs }));
+        }
+        self.http.send_empty(with_reason(request, reason)).await?;
+        self.cache.remove_member(self.guild_id, user_id);
+        Ok(())
+    }
+
+    /// Bans up to 200 users at once. Banned users are evicted from the cache.
+    pub async fn bulk_ban(&self, user_ids: &[Snowflake], delete_message_seconds: Option<u32>, reason: Option<&str>) -> Result<BulkBanResponse> {
+        if user_ids.is_empty() || user_ids.len() > 200 {
+            return Err(DiscordError::Validation("bulk ban requires between 1 and 200 user ids".to_string()));
+        }
+        let mut body = serde_json::json!({ "user_ids": user_ids });
+        if let Some(secs) = delete_message_seconds {
+            body["delete_message_seconds"] = secs.into();
+        }
+        let request = HttpRequest::post(format!("/guilds/{}/bulk-ban", self.guild_id)).body(body);
+        let response: BulkBanResponse = self.http.send_as(with_reason(request, reason)).await?;
+        for user_id in &response.banned_users {
+            self.cache.remove_member(self.guild_id, *user_id);
+        }
+        Ok(response)
+    }
+
+    pub async fn unban(&self, user_id: Snowflake, reason: Option<&str>) -> Result<()> {
+        let request = HttpRequest::delete(format!("/guilds/{}/bans/{}", self.guild_id, user_id));
+        self.http.send_empty(with_reason(request, reason)).await
+    }
+
+    pub async fn bans(&self) -> Result<Vec<Ban>> {
+        self.http.send_as(HttpRequest::get(format!("/guilds/{}/bans", self.guild_id))).await
+    }
+}
+
+pub struct GuildRoleManager {
+    guild_id: Snowflake,
+    cache: Arc<Cache>,
+    http: Arc<dyn Http>,
+}
+
+impl GuildRoleManager {
+    pub fn new(guild_id: Snowflake, cache: Arc<Cache>, http: Arc<dyn Http>) -> Self {
+        Self { guild_id, cache, http }
+    }
+
+    pub fn get(&self, role_id: Snowflake) -> Option<Role> {
+        self.cache.guilds.get(&self.guild_id)
+            .and_then(|g| g.roles.iter().find(|r| r.id == role_id).cloned())
+    }
+
+    pub async fn fetch_all(&self) -> Result<Vec<Role>> {
+        let roles: Vec<Role> = self.http.send_as(HttpRequest::get(format!("/guilds/{}/roles", self.guild_id))).await?;
+        for role in &roles {
+            self.cache.update_role(self.guild_id, role.clone());
+        }
+        Ok(roles)
+    }
+
+    pub async fn create(&self, role: EditRole, reason: Option<&str>) -> Result<Role> {
+        let request = HttpRequest::post(format!("/guilds/{}/roles", self.guild_id)).json(&role)?;
+        let role: Role = self.http.send_as(with_reason(request, reason)).await?;
+        self.cache.update_role(self.guild_id, role.clone());
+        Ok(role)
+    }
+
+    pub async fn edit(&self, role_id: Snowflake, role: EditRole, reason: Option<&str>) -> Result<Role> {
+        let request = HttpRequest::patch(format!("/guilds/{}/roles/{}", self.guild_id, role_id)).json(&role)?;
+        let role: Role = self.http.send_as(with_reason(request, reason)).await?;
+        self.cache.update_role(self.guild_id, role.clone());
+        Ok(role)
+    }
+
+    /// Moves roles; Discord answers with the full role list, which refreshes the cache.
+    pub async fn reorder(&self, positions: &[ModifyRolePosition], reason: Option<&str>) -> Result<Vec<Role>> {
+        let request = HttpRequest::patch(format!("/guilds/{}/roles", self.guild_id)).json(&positions)?;
+        let roles: Vec<Role> = self.http.send_as(with_reason(request, reason)).await?;
+        for role in &roles {
+            self.cache.update_role(self.guild_id, role.clone());
+        }
+        Ok(roles)
+    }
+
+    pub async fn delete(&self, role_id: Snowflake, reason: Option<&str>) -> Result<()> {
+        let request = HttpRequest::delete(format!("/guilds/{}/roles/{}", self.guild_id, role_id));
+        self.http.send_empty(with_reason(request, reason)).await?;
+        self.cache.remove_role(self.guild_id, role_id);
+        Ok(())
+    }
+}
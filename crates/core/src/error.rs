@@ -1,3 +1,4 @@
+use std::fmt;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,6 +9,9 @@ pub enum DiscordError {
     #[error("HTTP error: {0}")]
     Http(String),
 
+    #[error("{0}")]
+    Api(ApiError),
+
     #[error("Rate limit exceeded")]
     RateLimit,
 
@@ -25,4 +29,241 @@ pub enum DiscordError {
 
     #[error("Configuration error: {0}")]
     Configuration(String),
+
+    /// An RFC 6749 error from an OAuth2 token endpoint, e.g. `invalid_grant`.
+    #[error("OAuth2 error: {error}{}", .description.as_ref().map(|d| format!(": {}", d)).unwrap_or_default())]
+    OAuth2 {
+        error: String,
+        description: Option<String>,
+    },
+}
+
+impl DiscordError {
+    /// The JSON error code, if this is an error response from the Discord API.
+    pub fn api_code(&self) -> Option<JsonErrorCode> {
+        match self {
+            DiscordError::Api(e) => Some(e.code),
+            _ => None,
+        }
+    }
+}
+
+/// An error response returned by the Discord REST API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// HTTP status code of the response.
+    pub status: u16,
+    pub code: JsonErrorCode,
+    pub message: String,
+    /// Field-level validation errors, flattened from Discord's nested `errors` object.
+    pub errors: Vec<FieldError>,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Discord API error {} (status {}): {}",
+            u32::from(self.code),
+            self.status,
+            self.message
+        )?;
+        for error in &self.errors {
+            write!(f, " | {}: {} ({})", error.path, error.message, error.code)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single validation error, e.g. `embeds.0.title` / `BASE_TYPE_MAX_LENGTH`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Dot-separated path to the offending field.
+    pub path: String,
+    pub code: String,
+    pub message: String,
+}
+
+/// Well-known Discord JSON error codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonErrorCode {
+    General,
+    UnknownAccount,
+    UnknownApplication,
+    UnknownChannel,
+    UnknownGuild,
+    UnknownIntegration,
+    UnknownInvite,
+    UnknownMember,
+    UnknownMessage,
+    UnknownOverwrite,
+    UnknownRole,
+    UnknownToken,
+    UnknownUser,
+    UnknownEmoji,
+    UnknownWebhook,
+    UnknownBan,
+    UnknownSticker,
+    UnknownInteraction,
+    UnknownApplicationCommand,
+    BotsCannotUseEndpoint,
+    OnlyBotsCanUseEndpoint,
+    MaxGuildsReached,
+    MaxPinsReached,
+    MaxRolesReached,
+    MaxWebhooksReached,
+    MaxEmojisReached,
+    MaxReactionsReached,
+    MaxChannelsReached,
+    Unauthorized,
+    VerificationRequired,
+    RequestTooLarge,
+    InteractionAlreadyAcknowledged,
+    MissingAccess,
+    InvalidAccountType,
+    CannotExecuteOnDmChannel,
+    WidgetDisabled,
+    CannotEditOtherUsersMessage,
+    CannotSendEmptyMessage,
+    CannotSendMessagesToUser,
+    CannotSendMessagesInNonTextChannel,
+    InvalidOAuth2State,
+    MissingPermissions,
+    InvalidToken,
+    NoteTooLong,
+    InvalidBulkDeleteCount,
+    CannotPinInOtherChannel,
+    InvalidOrTakenInviteCode,
+    CannotExecuteOnSystemMessage,
+    InvalidOAuth2AccessToken,
+    MessageTooOldToBulkDelete,
+    InvalidFormBody,
+    InvalidWebhookToken,
+    ThreadArchived,
+    ReactionBlocked,
+    Unknown(u32),
+}
+
+impl From<u32> for JsonErrorCode {
+    fn from(v: u32) -> Self {
+        match v {
+            0 => JsonErrorCode::General,
+            10001 => JsonErrorCode::UnknownAccount,
+            10002 => JsonErrorCode::UnknownApplication,
+            10003 => JsonErrorCode::UnknownChannel,
+            10004 => JsonErrorCode::UnknownGuild,
+            10005 => JsonErrorCode::UnknownIntegration,
+            10006 => JsonErrorCode::UnknownInvite,
+            10007 => JsonErrorCode::UnknownMember,
+            10008 => JsonErrorCode::UnknownMessage,
+            10009 => JsonErrorCode::UnknownOverwrite,
+            10011 => JsonErrorCode::UnknownRole,
+            10012 => JsonErrorCode::UnknownToken,
+            10013 => JsonErrorCode::UnknownUser,
+            10014 => JsonErrorCode::UnknownEmoji,
+            10015 => JsonErrorCode::UnknownWebhook,
+            10026 => JsonErrorCode::UnknownBan,
+            10060 => JsonErrorCode::UnknownSticker,
+            10062 => JsonErrorCode::UnknownInteraction,
+            10063 => JsonErrorCode::UnknownApplicationCommand,
+            20001 => JsonErrorCode::BotsCannotUseEndpoint,
+            20002 => JsonErrorCode::OnlyBotsCanUseEndpoint,
+            30001 => JsonErrorCode::MaxGuildsReached,
+            30003 => JsonErrorCode::MaxPinsReached,
+            30005 => JsonErrorCode::MaxRolesReached,
+            30007 => JsonErrorCode::MaxWebhooksReached,
+            30008 => JsonErrorCode::MaxEmojisReached,
+            30010 => JsonErrorCode::MaxReactionsReached,
+            30013 => JsonErrorCode::MaxChannelsReached,
+            40001 => JsonErrorCode::Unauthorized,
+            40002 => JsonErrorCode::VerificationRequired,
+            40005 => JsonErrorCode::RequestTooLarge,
+            40060 => JsonErrorCode::InteractionAlreadyAcknowledged,
+            50001 => JsonErrorCode::MissingAccess,
+            50002 => JsonErrorCode::InvalidAccountType,
+            50003 => JsonErrorCode::CannotExecuteOnDmChannel,
+            50004 => JsonErrorCode::WidgetDisabled,
+            50005 => JsonErrorCode::CannotEditOtherUsersMessage,
+            50006 => JsonErrorCode::CannotSendEmptyMessage,
+            50007 => JsonErrorCode::CannotSendMessagesToUser,
+            50008 => JsonErrorCode::CannotSendMessagesInNonTextChannel,
+            50012 => JsonErrorCode::InvalidOAuth2State,
+            50013 => JsonErrorCode::MissingPermissions,
+            50014 => JsonErrorCode::InvalidToken,
+            50015 => JsonErrorCode::NoteTooLong,
+            50016 => JsonErrorCode::InvalidBulkDeleteCount,
+            50019 => JsonErrorCode::CannotPinInOtherChannel,
+            50020 => JsonErrorCode::InvalidOrTakenInviteCode,
+            50021 => JsonErrorCode::CannotExecuteOnSystemMessage,
+            50025 => JsonErrorCode::InvalidOAuth2AccessToken,
+            50027 => JsonErrorCode::InvalidWebhookToken,
+            50034 => JsonErrorCode::MessageTooOldToBulkDelete,
+            50035 => JsonErrorCode::InvalidFormBody,
+            50083 => JsonErrorCode::ThreadArchived,
+            90001 => JsonErrorCode::ReactionBlocked,
+            _ => JsonErrorCode::Unknown(v),
+        }
+    }
+}
+
+impl From<JsonErrorCode> for u32 {
+    fn from(v: JsonErrorCode) -> Self {
+        match v {
+            JsonErrorCode::General => 0,
+            JsonErrorCode::UnknownAccount => 10001,
+            JsonErrorCode::UnknownApplication => 10002,
+            JsonErrorCode::UnknownChannel => 10003,
+            JsonErrorCode::UnknownGuild => 10004,
+            JsonErrorCode::UnknownIntegration => 10005,
+            JsonErrorCode::UnknownInvite => 10006,
+            JsonErrorCode::UnknownMember => 10007,
+            JsonErrorCode::UnknownMessage => 10008,
+            JsonErrorCode::UnknownOverwrite => 10009,
+            JsonErrorCode::UnknownRole => 10011,
+            JsonErrorCode::UnknownToken => 10012,
+            JsonErrorCode::UnknownUser => 10013,
+            JsonErrorCode::UnknownEmoji => 10014,
+            JsonErrorCode::UnknownWebhook => 10015,
+            JsonErrorCode::UnknownBan => 10026,
+            JsonErrorCode::UnknownSticker => 10060,
+            JsonErrorCode::UnknownInteraction => 10062,
+            JsonErrorCode::UnknownApplicationCommand => 10063,
+            JsonErrorCode::BotsCannotUseEndpoint => 20001,
+            JsonErrorCode::OnlyBotsCanUseEndpoint => 20002,
+            JsonErrorCode::MaxGuildsReached => 30001,
+            JsonErrorCode::MaxPinsReached => 30003,
+            JsonErrorCode::MaxRolesReached => 30005,
+            JsonErrorCode::MaxWebhooksReached => 30007,
+            JsonErrorCode::MaxEmojisReached => 30008,
+            JsonErrorCode::MaxReactionsReached => 30010,
+            JsonErrorCode::MaxChannelsReached => 30013,
+            JsonErrorCode::Unauthorized => 40001,
+            JsonErrorCode::VerificationRequired => 40002,
+            JsonErrorCode::RequestTooLarge => 40005,
+            JsonErrorCode::InteractionAlreadyAcknowledged => 40060,
+            JsonErrorCode::MissingAccess => 50001,
+            JsonErrorCode::InvalidAccountType => 50002,
+            JsonErrorCode::CannotExecuteOnDmChannel => 50003,
+            JsonErrorCode::WidgetDisabled => 50004,
+            JsonErrorCode::CannotEditOtherUsersMessage => 50005,
+            JsonErrorCode::CannotSendEmptyMessage => 50006,
+            JsonErrorCode::CannotSendMessagesToUser => 50007,
+            JsonErrorCode::CannotSendMessagesInNonTextChannel => 50008,
+            JsonErrorCode::InvalidOAuth2State => 50012,
+            JsonErrorCode::MissingPermissions => 50013,
+            JsonErrorCode::InvalidToken => 50014,
+            JsonErrorCode::NoteTooLong => 50015,
+            JsonErrorCode::InvalidBulkDeleteCount => 50016,
+            JsonErrorCode::CannotPinInOtherChannel => 50019,
+            JsonErrorCode::InvalidOrTakenInviteCode => 50020,
+            JsonErrorCode::CannotExecuteOnSystemMessage => 50021,
+            JsonErrorCode::InvalidOAuth2AccessToken => 50025,
+            JsonErrorCode::InvalidWebhookToken => 50027,
+            JsonErrorCode::MessageTooOldToBulkDelete => 50034,
+            JsonErrorCode::InvalidFormBody => 50035,
+            JsonErrorCode::ThreadArchived => 50083,
+            JsonErrorCode::ReactionBlocked => 90001,
+            JsonErrorCode::Unknown(v) => v,
+        }
+    }
 }
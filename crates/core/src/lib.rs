@@ -6,7 +6,7 @@ pub mod context;
 pub mod traits;
 
 pub use config::Config;
-pub use error::DiscordError;
+pub use error::{DiscordError, ApiError, FieldError, JsonErrorCode};
 pub use intents::Intents;
 pub use snowflake::Snowflake;
 pub use context::Context;
@@ -1,13 +1,202 @@
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
-use crate::Result;
+use serde::Serialize;
+use crate::{DiscordError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+/// A REST call made through `Http::send`.
+///
+/// ```ignore
+/// let request = HttpRequest::put(format!("/guilds/{}/bans/{}", guild_id, user_id))
+///     .json(&serde_json::json!({ "delete_message_seconds": 3600 }))?
+///     .reason("spam");
+/// ctx.http.send(request).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    /// Path below the API version, e.g. `/channels/1/messages`, without query.
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub body: Option<serde_json::Value>,
+    /// Sent as `X-Audit-Log-Reason`.
+    pub reason: Option<String>,
+    /// When non-empty the request is sent as multipart, with `body` in `payload_json`.
+    pub files: Vec<FileUpload>,
+}
+
+impl HttpRequest {
+    pub fn new(method: HttpMethod, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            query: Vec::new(),
+            body: None,
+            reason: None,
+            files: Vec::new(),
+        }
+    }
+
+    pub fn get(path: impl Into<String>) -> Self {
+        Self::new(HttpMethod::Get, path)
+    }
+
+    pub fn post(path: impl Into<String>) -> Self {
+        Self::new(HttpMethod::Post, path)
+    }
+
+    pub fn put(path: impl Into<String>) -> Self {
+        Self::new(HttpMethod::Put, path)
+    }
+
+    pub fn patch(path: impl Into<String>) -> Self {
+        Self::new(HttpMethod::Patch, path)
+    }
+
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self::new(HttpMethod::Delete, path)
+    }
+
+    pub fn body(mut self, body: serde_json::Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn json(self, body: &impl Serialize) -> Result<Self> {
+        let body = serde_json::to_value(body).map_err(|e| DiscordError::Serialization(e.to_string()))?;
+        Ok(self.body(body))
+    }
+
+    pub fn query_param(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.query.push((key.into(), value.to_string()));
+        self
+    }
+
+    /// Adds every non-null field of a query struct, such as `GetMessages`.
+    pub fn query(mut self, query: &impl Serialize) -> Result<Self> {
+        self.query.extend(query_pairs(query)?);
+        Ok(self)
+    }
+
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    pub fn file(mut self, file: FileUpload) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    pub fn files(mut self, files: Vec<FileUpload>) -> Self {
+        self.files = files;
+        self
+    }
+}
+
+/// Key/value pairs of a query struct, skipping `None` fields. Strings are
+/// taken as-is, other values use their JSON form (`true`, `50`).
+pub fn query_pairs(query: &impl Serialize) -> Result<Vec<(String, String)>> {
+    let value = serde_json::to_value(query).map_err(|e| DiscordError::Serialization(e.to_string()))?;
+    let map = match value {
+        serde_json::Value::Object(map) => map,
+        serde_json::Value::Null => return Ok(Vec::new()),
+        _ => return Err(DiscordError::Serialization("Query must serialize to an object".to_string())),
+    };
+    Ok(map
+        .into_iter()
+        .filter_map(|(key, value)| match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) => Some((key, s)),
+            other => Some((key, other.to_string())),
+        })
+        .collect())
+}
 
 #[async_trait]
 pub trait Http: Send + Sync {
-    // Basic interface for now, will expand as needed
-    async fn get(&self, path: &str) -> Result<serde_json::Value>;
-    async fn post(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value>;
-    async fn patch(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value>;
-    async fn delete(&self, path: &str) -> Result<serde_json::Value>;
-    // Add specific methods as needed by Context shortcuts
+    /// Sends a request, returning the JSON body (`Value::Null` for 204s).
+    async fn send(&self, request: HttpRequest) -> Result<serde_json::Value>;
+
+    async fn get(&self, path: &str) -> Result<serde_json::Value> {
+        self.send(HttpRequest::get(path)).await
+    }
+
+    async fn post(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
+        self.send(HttpRequest::post(path).body(body)).await
+    }
+
+    async fn patch(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
+        self.send(HttpRequest::patch(path).body(body)).await
+    }
+
+    /// `body` may be `Value::Null` for PUTs without a payload (role adds, bans, pins).
+    async fn put(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
+        let request = HttpRequest::put(path);
+        let request = if body.is_null() { request } else { request.body(body) };
+        self.send(request).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<serde_json::Value> {
+        self.send(HttpRequest::delete(path)).await
+    }
+
+    /// POST as `multipart/form-data`: `payload` goes in `payload_json`, each
+    /// file in `files[n]`.
+    async fn post_multipart(&self, path: &str, payload: serde_json::Value, files: Vec<FileUpload>) -> Result<serde_json::Value> {
+        self.send(HttpRequest::post(path).body(payload).files(files)).await
+    }
+
+    async fn patch_multipart(&self, path: &str, payload: serde_json::Value, files: Vec<FileUpload>) -> Result<serde_json::Value> {
+        self.send(HttpRequest::patch(path).body(payload).files(files)).await
+    }
+}
+
+/// Typed helpers for any `Http`, including `Arc<dyn Http>`.
+#[async_trait]
+pub trait HttpExt: Http {
+    /// Sends `request` and deserializes the response.
+    async fn send_as<T: DeserializeOwned>(&self, request: HttpRequest) -> Result<T> {
+        let value = self.send(request).await?;
+        serde_json::from_value(value).map_err(|e| DiscordError::Serialization(e.to_string()))
+    }
+
+    /// Sends `request`, discarding the response body.
+    async fn send_empty(&self, request: HttpRequest) -> Result<()> {
+        self.send(request).await?;
+        Ok(())
+    }
+}
+
+impl<H: Http + ?Sized> HttpExt for H {}
+
+/// A file sent along with a JSON payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileUpload {
+    pub filename: String,
+    pub data: Vec<u8>,
+    pub content_type: Option<String>,
+}
+
+impl FileUpload {
+    pub fn new(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            filename: filename.into(),
+            data: data.into(),
+            content_type: None,
+        }
+    }
+
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
 }
@@ -426,6 +426,26 @@ impl GatewayManager {
                         serde_json::from_value(d)
                             .map_err(|e| DiscordError::Serialization(e.to_string()))?,
                     ),
+                    "GUILD_AUDIT_LOG_ENTRY_CREATE" => Event::GuildAuditLogEntryCreate(Box::new(
+                        serde_json::from_value(d)
+                            .map_err(|e| DiscordError::Serialization(e.to_string()))?,
+                    )),
+                    "AUTO_MODERATION_RULE_CREATE" => Event::AutoModerationRuleCreate(Box::new(
+                        serde_json::from_value(d)
+                            .map_err(|e| DiscordError::Serialization(e.to_string()))?,
+                    )),
+                    "AUTO_MODERATION_RULE_UPDATE" => Event::AutoModerationRuleUpdate(Box::new(
+                        serde_json::from_value(d)
+                            .map_err(|e| DiscordError::Serialization(e.to_string()))?,
+                    )),
+                    "AUTO_MODERATION_RULE_DELETE" => Event::AutoModerationRuleDelete(Box::new(
+                        serde_json::from_value(d)
+                            .map_err(|e| DiscordError::Serialization(e.to_string()))?,
+                    )),
+                    "AUTO_MODERATION_ACTION_EXECUTION" => Event::AutoModerationActionExecution(Box::new(
+                        serde_json::from_value(d)
+                            .map_err(|e| DiscordError::Serialization(e.to_string()))?,
+                    )),
                     _ => Event::Unknown,
                 };
 
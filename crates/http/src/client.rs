@@ -1,43 +1,268 @@
 use discord_rs_core::{Config, DiscordError, Result};
-use discord_rs_core::traits::Http;
+use discord_rs_core::traits::{FileUpload, Http, HttpMethod, HttpRequest};
 use reqwest::{Client as ReqwestClient, header, Method, StatusCode};
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
 use async_trait::async_trait;
-use crate::ratelimit::RateLimiter;
-use crate::routing::Route;
-use crate::error::DiscordApiError;
+use crate::ratelimit::{RateLimitBackend, RateLimitScope, RateLimiter};
+use crate::routing::{append_query, Endpoint, Route};
+use crate::error::error_from_response;
+use crate::invalid_requests::{InvalidRequestConfig, InvalidRequestTracker};
+use crate::retry::RetryPolicy;
+use crate::middleware::{Middleware, Middlewares, RateLimitInfo, RequestInfo, ResponseInfo};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
-const API_VERSION: u8 = 10;
-const BASE_URL: &str = "https://discord.com/api/v10";
+pub const DEFAULT_API_VERSION: u8 = 10;
+pub const DEFAULT_BASE_URL: &str = "https://discord.com/api";
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const USER_AGENT: &str = "DiscordBot (discord.rs, 0.1.0)";
+
+/// How the token in `Config` is sent in the `Authorization` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TokenType {
+    #[default]
+    Bot,
+    /// OAuth2 access token, acting on behalf of a user.
+    Bearer,
+}
 
 #[derive(Debug, Clone)]
 pub struct RestClient {
     http: ReqwestClient,
     config: Arc<Config>,
-    ratelimiter: Arc<RateLimiter>,
+    token_type: TokenType,
+    /// `None` for clients that only use token-authenticated webhook routes.
+    authorization: Option<header::HeaderValue>,
+    user_agent: header::HeaderValue,
+    api_base: String,
+    timeout: Option<Duration>,
+    proxy_mode: bool,
+    retry_policy: RetryPolicy,
+    middleware: Middlewares,
+    ratelimiter: Arc<dyn RateLimitBackend>,
+    invalid_requests: Arc<InvalidRequestTracker>,
 }
 
-#[async_trait]
-impl Http for RestClient {
-    async fn get(&self, path: &str) -> Result<serde_json::Value> {
-        self.request(Method::GET, path, None, None).await
+/// Builder for a `RestClient` that doesn't talk to Discord directly, or
+/// needs a different timeout / user agent.
+#[derive(Debug, Clone)]
+pub struct RestClientBuilder {
+    config: Arc<Config>,
+    token_type: TokenType,
+    authenticated: bool,
+    base_url: String,
+    api_version: u8,
+    timeout: Option<Duration>,
+    user_agent_suffix: Option<String>,
+    proxy_mode: bool,
+    http: Option<ReqwestClient>,
+    invalid_requests: InvalidRequestConfig,
+    retry_policy: RetryPolicy,
+    middleware: Middlewares,
+    ratelimiter: Option<Arc<dyn RateLimitBackend>>,
+}
+
+impl RestClientBuilder {
+    pub fn new(config: Arc<Config>) -> Self {
+        Self {
+            config,
+            token_type: TokenType::Bot,
+            authenticated: true,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_version: DEFAULT_API_VERSION,
+            timeout: Some(DEFAULT_TIMEOUT),
+            user_agent_suffix: None,
+            proxy_mode: false,
+            http: None,
+            invalid_requests: InvalidRequestConfig::default(),
+            retry_policy: RetryPolicy::default(),
+            middleware: Middlewares::default(),
+            ratelimiter: None,
+        }
+    }
+
+    /// Builder for a client that sends no `Authorization` header, e.g. for
+    /// webhook routes, where the token is part of the path.
+    pub fn unauthenticated() -> Self {
+        Self {
+            authenticated: false,
+            ..Self::new(Arc::new(Config::new("")))
+        }
+    }
+
+    pub fn token_type(mut self, token_type: TokenType) -> Self {
+        self.token_type = token_type;
+        self
+    }
+
+    /// Base URL without the version segment, e.g. `http://localhost:8080/api`.
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    pub fn api_version(mut self, version: u8) -> Self {
+        self.api_version = version;
+        self
+    }
+
+    /// Per-request timeout. `None` waits indefinitely.
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Appended to the default `DiscordBot (...)` user agent.
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Disables the local rate limiter, for when requests go through a
+    /// proxy that handles rate limits itself.
+    pub fn proxy_mode(mut self, enabled: bool) -> Self {
+        self.proxy_mode = enabled;
+        self
     }
 
-    async fn post(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
-        self.request(Method::POST, path, Some(body), None).await
+    /// Uses a pre-built reqwest client instead of creating one.
+    pub fn http_client(mut self, client: ReqwestClient) -> Self {
+        self.http = Some(client);
+        self
     }
 
-    async fn patch(&self, path: &str, body: serde_json::Value) -> Result<serde_json::Value> {
-        self.request(Method::PATCH, path, Some(body), None).await
+    pub fn invalid_request_config(mut self, config: InvalidRequestConfig) -> Self {
+        self.invalid_requests = config;
+        self
     }
 
-    async fn delete(&self, path: &str) -> Result<serde_json::Value> {
-        self.request(Method::DELETE, path, None, None).await
+    /// Rate limit state shared with other clients or processes. Defaults to
+    /// a fresh in-memory `RateLimiter`.
+    pub fn ratelimiter(mut self, ratelimiter: Arc<dyn RateLimitBackend>) -> Self {
+        self.ratelimiter = Some(ratelimiter);
+        self
+    }
+
+    /// Adds a middleware; they run in the order added.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Retry policy for 5xx responses and transport errors.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    pub fn build(self) -> Result<RestClient> {
+        let user_agent = match &self.user_agent_suffix {
+            Some(suffix) => format!("{} {}", USER_AGENT, suffix),
+            None => USER_AGENT.to_string(),
+        };
+        let user_agent = header::HeaderValue::from_str(&user_agent)
+            .map_err(|e| DiscordError::Validation(e.to_string()))?;
+
+        let http = match self.http {
+            Some(http) => http,
+            None => ReqwestClient::builder()
+                .build()
+                .map_err(|e| DiscordError::Http(e.to_string()))?,
+        };
+
+        Ok(RestClient {
+            http,
+            authorization: if self.authenticated {
+                Some(authorization(&self.config, self.token_type)?)
+            } else {
+                None
+            },
+            config: self.config,
+            token_type: self.token_type,
+            user_agent,
+            api_base: format!("{}/v{}", self.base_url.trim_end_matches('/'), self.api_version),
+            timeout: self.timeout,
+            proxy_mode: self.proxy_mode,
+            retry_policy: self.retry_policy,
+            middleware: self.middleware,
+            ratelimiter: self.ratelimiter.unwrap_or_else(|| Arc::new(RateLimiter::new())),
+            invalid_requests: Arc::new(InvalidRequestTracker::new(self.invalid_requests)),
+        })
+    }
+}
+
+fn authorization(config: &Config, token_type: TokenType) -> Result<header::HeaderValue> {
+    let scheme = match token_type {
+        TokenType::Bot => "Bot",
+        TokenType::Bearer => "Bearer",
+    };
+    let mut value = header::HeaderValue::from_str(&format!("{} {}", scheme, config.token))
+        .map_err(|e| DiscordError::Validation(e.to_string()))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+#[async_trait]
+impl Http for RestClient {
+    async fn send(&self, request: HttpRequest) -> Result<serde_json::Value> {
+        let method = match request.method {
+            HttpMethod::Get => Method::GET,
+            HttpMethod::Post => Method::POST,
+            HttpMethod::Put => Method::PUT,
+            HttpMethod::Patch => Method::PATCH,
+            HttpMethod::Delete => Method::DELETE,
+        };
+
+        let path = append_query(&request.path, &request.query);
+        let reason = request.reason.as_deref();
+
+        if request.files.is_empty() {
+            self.request(method, &path, request.body, reason).await
+        } else {
+            let payload = request.body.unwrap_or_else(|| serde_json::json!({}));
+            let files = request.files;
+            self.request_multipart_retryable(method, &path, || payload_form(&payload, &files), reason)
+                .await
+        }
     }
 }
 
+fn parse_rate_limit(headers: &header::HeaderMap, body: &[u8]) -> RateLimitInfo {
+    let body_json: serde_json::Value = serde_json::from_slice(body)
+        .unwrap_or(serde_json::json!({}));
+
+    let scope = RateLimitScope::from_headers(headers);
+    let global = scope == Some(RateLimitScope::Global)
+        || headers.contains_key("x-ratelimit-global")
+        || body_json.get("global").and_then(|v| v.as_bool()).unwrap_or(false);
+    let retry_after = body_json.get("retry_after").and_then(|v| v.as_f64()).unwrap_or(0.0);
+
+    RateLimitInfo { retry_after, global, scope }
+}
+
+/// Builds the `payload_json` + `files[n]` form Discord expects for uploads.
+pub fn payload_form(payload: &serde_json::Value, files: &[FileUpload]) -> Result<reqwest::multipart::Form> {
+    use reqwest::multipart::{Form, Part};
+
+    let json = Part::text(payload.to_string())
+        .mime_str("application/json")
+        .map_err(|e| DiscordError::Http(e.to_string()))?;
+    let mut form = Form::new().part("payload_json", json);
+
+    for (i, file) in files.iter().enumerate() {
+        let mut part = Part::bytes(file.data.clone()).file_name(file.filename.clone());
+        if let Some(content_type) = &file.content_type {
+            part = part.mime_str(content_type).map_err(|e| DiscordError::Http(e.to_string()))?;
+        }
+        form = form.part(format!("files[{}]", i), part);
+    }
+
+    Ok(form)
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GetGatewayBot {
     pub url: String,
@@ -55,32 +280,107 @@ pub struct SessionStartLimit {
 
 impl RestClient {
     pub fn new(config: Arc<Config>) -> Result<Self> {
-        let mut headers = header::HeaderMap::new();
-        let auth_value = format!("Bot {}", config.token);
-        headers.insert(
-            header::AUTHORIZATION,
-            header::HeaderValue::from_str(&auth_value)
-                .map_err(|e| DiscordError::Validation(e.to_string()))?,
-        );
-        headers.insert(
-            header::USER_AGENT,
-            header::HeaderValue::from_static("DiscordBot (discord.rs, 0.1.0)"),
-        );
-
-        let http = ReqwestClient::builder()
-            .default_headers(headers)
+        RestClientBuilder::new(config).build()
+    }
+
+    pub fn builder(config: Arc<Config>) -> RestClientBuilder {
+        RestClientBuilder::new(config)
+    }
+
+    /// Client authenticating with an OAuth2 access token instead of a bot token.
+    pub fn bearer(access_token: impl Into<String>) -> Result<Self> {
+        RestClientBuilder::new(Arc::new(Config::new(access_token)))
+            .token_type(TokenType::Bearer)
             .build()
-            .map_err(|e| DiscordError::Http(e.to_string()))?;
+    }
+
+    pub fn token_type(&self) -> TokenType {
+        self.token_type
+    }
 
+    /// Copy of this client using `config`, keeping the transport settings
+    /// and sharing the rate limit state.
+    pub fn with_config(&self, config: Arc<Config>) -> Result<Self> {
         Ok(Self {
-            http,
+            authorization: Some(authorization(&config, self.token_type)?),
             config,
-            ratelimiter: Arc::new(RateLimiter::new()),
+            ..self.clone()
         })
     }
 
+    /// Copy of this client that sends no `Authorization` header, sharing
+    /// the rate limit state. Used for webhook routes, where the token is
+    /// part of the path.
+    pub fn without_authorization(&self) -> Self {
+        Self {
+            authorization: None,
+            ..self.clone()
+        }
+    }
+
+    /// Base URL including the version segment, e.g. `https://discord.com/api/v10`.
+    pub fn api_base(&self) -> &str {
+        &self.api_base
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The rate limit state, e.g. to hand to another client's builder.
+    pub fn ratelimiter(&self) -> &Arc<dyn RateLimitBackend> {
+        &self.ratelimiter
+    }
+
+    pub fn is_proxy_mode(&self) -> bool {
+        self.proxy_mode
+    }
+
+    /// Replaces the invalid request thresholds. Resets the tracked window.
+    pub fn invalid_request_config(mut self, config: InvalidRequestConfig) -> Self {
+        self.invalid_requests = Arc::new(InvalidRequestTracker::new(config));
+        self
+    }
+
+    pub fn invalid_requests(&self) -> &InvalidRequestTracker {
+        &self.invalid_requests
+    }
+
+    pub fn config(&self) -> &Arc<Config> {
+        &self.config
+    }
+
     pub async fn get_gateway_bot(&self) -> Result<GetGatewayBot> {
-        let value = self.request(Method::GET, "/gateway/bot", None, None).await?;
+        self.request_typed(Method::GET, &Endpoint::GatewayBot.path(), None, None).await
+    }
+
+    /// Fetches a file by absolute URL, e.g. a CDN image from
+    /// `discord_rs_model::cdn` or an attachment. Sent without the
+    /// `Authorization` header and outside the API rate limits.
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>> {
+        let mut req = self.http.get(url).header(header::USER_AGENT, self.user_agent.clone());
+        if let Some(timeout) = self.timeout {
+            req = req.timeout(timeout);
+        }
+
+        let response = req.send().await.map_err(|e| DiscordError::Http(e.to_string()))?;
+        let status = response.status();
+        let bytes = response.bytes().await.map_err(|e| DiscordError::Http(e.to_string()))?;
+        if !status.is_success() {
+            return Err(error_from_response(status.as_u16(), &bytes));
+        }
+        Ok(bytes.to_vec())
+    }
+
+    /// Same as `request`, deserializing the response body into `T`.
+    pub async fn request_typed<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+        reason: Option<&str>,
+    ) -> Result<T> {
+        let value = self.request(method, path, body, reason).await?;
         serde_json::from_value(value).map_err(|e| DiscordError::Serialization(e.to_string()))
     }
 
@@ -92,30 +392,85 @@ impl RestClient {
         body: Option<serde_json::Value>,
         reason: Option<&str>,
     ) -> Result<serde_json::Value> {
-        let route = Route::new(method.clone(), path);
-        let url = format!("{}{}", BASE_URL, path);
+        self.request_with_retry(method, path, body, reason, &self.retry_policy).await
+    }
 
-        loop {
-            self.ratelimiter.await_bucket(&route).await;
+    /// Same as `request`, with a retry policy other than the client's.
+    pub async fn request_with_retry(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+        reason: Option<&str>,
+        retry: &RetryPolicy,
+    ) -> Result<serde_json::Value> {
+        self.execute(method, path, reason, retry, |req| {
+            Ok(match &body {
+                Some(b) => req.json(b),
+                None => req,
+            })
+        })
+        .await
+    }
 
-            let mut req = self.http.request(method.clone(), &url);
+    /// Shared send loop: rate limits, invalid request tracking and retries.
+    /// `attach` adds the body and is called again for every attempt.
+    async fn execute<F>(
+        &self,
+        method: Method,
+        path: &str,
+        reason: Option<&str>,
+        retry: &RetryPolicy,
+        attach: F,
+    ) -> Result<serde_json::Value>
+    where
+        F: Fn(reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder>,
+    {
+        let route = Route::new(method.clone(), path);
+        let url = format!("{}{}", self.api_base, path);
 
-            if let Some(r) = reason {
-                let encoded = utf8_percent_encode(r, NON_ALPHANUMERIC).to_string();
-                if let Ok(hv) = header::HeaderValue::from_str(&encoded) {
-                    req = req.header("X-Audit-Log-Reason", hv);
-                }
-            }
+        let mut rate_limit_retries = 0;
+        let mut attempt = 0;
 
-            if let Some(b) = &body {
-                req = req.json(b);
+        loop {
+            self.invalid_requests.check()?;
+            if !self.proxy_mode {
+                self.ratelimiter.acquire(&route).await;
             }
 
-            let response = req.send().await.map_err(|e| DiscordError::Http(e.to_string()))?;
+            attempt += 1;
+            let info = RequestInfo { route: &route, attempt };
+            let extra_headers = self.middleware.before_send(&info);
+            let req = attach(self.build_request(&method, &url, reason).headers(extra_headers))?;
+
+            let started = Instant::now();
+            let response = match req.send().await {
+                Ok(response) => response,
+                Err(e) => {
+                    let retryable = retry.should_retry_error(&method, &e, attempt);
+                    let error = DiscordError::Http(e.to_string());
+                    self.middleware.on_error(&info, &error);
+                    if !retryable {
+                        return Err(error);
+                    }
+                    let delay = retry.backoff(attempt);
+                    tracing::warn!("{} failed ({}), retrying in {:?}", route.template(), e, delay);
+                    sleep(delay).await;
+                    continue;
+                }
+            };
             let status = response.status();
             let headers = response.headers().clone();
-
-            self.ratelimiter.update(&route, &headers).await;
+            self.middleware.after_response(&info, &ResponseInfo {
+                status,
+                headers: &headers,
+                elapsed: started.elapsed(),
+            });
+
+            if !self.proxy_mode {
+                self.ratelimiter.update(&route, &headers).await;
+            }
+            self.track_invalid(status, &headers);
 
             if status.is_success() {
                 // Handle 204 No Content
@@ -128,30 +483,96 @@ impl RestClient {
 
             if status == StatusCode::TOO_MANY_REQUESTS {
                 let bytes = response.bytes().await.map_err(|e| DiscordError::Http(e.to_string()))?;
-                let body_json: serde_json::Value = serde_json::from_slice(&bytes)
-                    .unwrap_or(serde_json::json!({}));
-                
-                let is_global = body_json.get("global").and_then(|v| v.as_bool()).unwrap_or(false);
-                let retry_after = body_json.get("retry_after").and_then(|v| v.as_f64()).unwrap_or(0.0);
-
-                if is_global {
-                    self.ratelimiter.handle_global_limit(retry_after).await;
-                } else {
-                    // Bucket specific 429. The `update` call above should have set the reset time.
-                    // We just continue loop to wait in `await_bucket`.
-                    // But we might want to log it.
-                    tracing::warn!("Rate limited on {}. Retry after {}s", path, retry_after);
+                let ratelimit = parse_rate_limit(&headers, &bytes);
+                self.middleware.on_ratelimit(&info, &ratelimit);
+                rate_limit_retries += 1;
+                if rate_limit_retries > self.invalid_requests.config().max_rate_limit_retries {
+                    tracing::warn!("Giving up on {} after {} rate limited attempts", route.template(), rate_limit_retries);
+                    self.record_rate_limit(&route, &ratelimit).await;
+                    return Err(DiscordError::RateLimit);
                 }
+                self.wait_rate_limited(&route, &ratelimit).await;
+                // 429s don't count as failed attempts.
+                attempt -= 1;
+                continue;
+            }
+
+            if retry.should_retry_status(&method, status, attempt) {
+                let delay = retry.backoff(attempt);
+                tracing::warn!("{} returned {}, retrying in {:?}", route.template(), status, delay);
+                sleep(delay).await;
                 continue;
             }
 
             // Error parsing
             let bytes = response.bytes().await.map_err(|e| DiscordError::Http(e.to_string()))?;
-            if let Ok(api_error) = serde_json::from_slice::<DiscordApiError>(&bytes) {
-                return Err(DiscordError::Http(api_error.to_string()));
+            return Err(error_from_response(status.as_u16(), &bytes));
+        }
+    }
+
+    fn build_request(&self, method: &Method, url: &str, reason: Option<&str>) -> reqwest::RequestBuilder {
+        let mut req = self.http.request(method.clone(), url)
+            .header(header::USER_AGENT, self.user_agent.clone());
+
+        if let Some(authorization) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, authorization.clone());
+        }
+
+        if let Some(timeout) = self.timeout {
+            req = req.timeout(timeout);
+        }
+
+        if let Some(r) = reason {
+            let encoded = utf8_percent_encode(r, NON_ALPHANUMERIC).to_string();
+            if let Ok(hv) = header::HeaderValue::from_str(&encoded) {
+                req = req.header("X-Audit-Log-Reason", hv);
             }
-            
-            return Err(DiscordError::Http(format!("Request failed with status: {}", status)));
+        }
+
+        req
+    }
+
+    /// Counts 401/403/429 towards the invalid request window. Shared-scope
+    /// 429s are excluded, as Discord does not count them either.
+    fn track_invalid(&self, status: StatusCode, headers: &header::HeaderMap) {
+        let invalid = match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => true,
+            StatusCode::TOO_MANY_REQUESTS => {
+                RateLimitScope::from_headers(headers) != Some(RateLimitScope::Shared)
+            }
+            _ => false,
+        };
+        if invalid {
+            self.invalid_requests.record();
+        }
+    }
+
+    /// Records a 429 so the next `acquire` waits out the limit.
+    async fn wait_rate_limited(&self, route: &Route<'_>, ratelimit: &RateLimitInfo) {
+        let retry_after = ratelimit.retry_after;
+        if self.proxy_mode {
+            // No local limiter to record into; just back off before retrying.
+            sleep(Duration::from_secs_f64(retry_after.max(0.0))).await;
+        } else if ratelimit.global {
+            self.ratelimiter.global_limited(retry_after).await;
+        } else {
+            tracing::warn!("Rate limited on {} (scope {:?}). Retry after {}s", route.template(), ratelimit.scope, retry_after);
+            self.ratelimiter.bucket_limited(route, retry_after).await;
+        }
+    }
+
+    /// Records a 429 for later requests without waiting it out, for when
+    /// this request gives up.
+    async fn record_rate_limit(&self, route: &Route<'_>, ratelimit: &RateLimitInfo) {
+        let retry_after = ratelimit.retry_after;
+        if self.proxy_mode {
+            // Nothing to record into.
+        } else if ratelimit.global {
+            // The limiter holds other requests back while it sleeps.
+            let ratelimiter = self.ratelimiter.clone();
+            tokio::spawn(async move { ratelimiter.global_limited(retry_after).await });
+        } else {
+            self.ratelimiter.bucket_limited(route, retry_after).await;
         }
     }
 
@@ -188,64 +609,9 @@ impl RestClient {
     where
         F: Fn() -> Result<reqwest::multipart::Form> + Send + Sync
     {
-        let route = Route::new(method.clone(), path);
-        let url = format!("{}{}", BASE_URL, path);
-
-        loop {
-            self.ratelimiter.await_bucket(&route).await;
-
-            let mut req = self.http.request(method.clone(), &url);
-
-            if let Some(r) = reason {
-                let encoded = utf8_percent_encode(r, NON_ALPHANUMERIC).to_string();
-                if let Ok(hv) = header::HeaderValue::from_str(&encoded) {
-                    req = req.header("X-Audit-Log-Reason", hv);
-                }
-            }
-
-            // Rebuild the form
-            let form = make_form()?;
-            req = req.multipart(form);
-
-            let response = req.send().await.map_err(|e| DiscordError::Http(e.to_string()))?;
-            let status = response.status();
-            let headers = response.headers().clone();
-
-            self.ratelimiter.update(&route, &headers).await;
-
-            if status.is_success() {
-                 if status == StatusCode::NO_CONTENT {
-                    return Ok(serde_json::Value::Null);
-                }
-                return response.json::<serde_json::Value>().await
-                    .map_err(|e| DiscordError::Serialization(e.to_string()));
-            }
-
-            if status == StatusCode::TOO_MANY_REQUESTS {
-                let bytes = response.bytes().await.map_err(|e| DiscordError::Http(e.to_string()))?;
-                let body_json: serde_json::Value = serde_json::from_slice(&bytes)
-                    .unwrap_or(serde_json::json!({}));
-                
-                let is_global = body_json.get("global").and_then(|v| v.as_bool()).unwrap_or(false);
-                let retry_after = body_json.get("retry_after").and_then(|v| v.as_f64()).unwrap_or(0.0);
-
-                if is_global {
-                    self.ratelimiter.handle_global_limit(retry_after).await;
-                } else {
-                    tracing::warn!("Rate limited on multipart {}. Retry after {}s", path, retry_after);
-                    tokio::time::sleep(tokio::time::Duration::from_secs_f64(retry_after)).await;
-                }
-                
-                // Retry loop continues here
-                continue;
-            }
-
-            let bytes = response.bytes().await.map_err(|e| DiscordError::Http(e.to_string()))?;
-            if let Ok(api_error) = serde_json::from_slice::<DiscordApiError>(&bytes) {
-                return Err(DiscordError::Http(api_error.to_string()));
-            }
-            
-            return Err(DiscordError::Http(format!("Request failed with status: {}", status)));
-        }
+        self.execute(method, path, reason, &self.retry_policy, |req| {
+            Ok(req.multipart(make_form()?))
+        })
+        .await
     }
-}
\ No newline at end of file
+}
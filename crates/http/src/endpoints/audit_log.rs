@@ -0,0 +1,14 @@
+use crate::client::RestClient;
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::audit_log::{AuditLog, GetGuildAuditLog};
+use reqwest::Method;
+
+impl RestClient {
+    /// One page of the guild's audit log; see `stream_guild_audit_log` for
+    /// all entries. Requires `VIEW_AUDIT_LOG`.
+    pub async fn get_guild_audit_log(&self, guild_id: Snowflake, query: &GetGuildAuditLog) -> Result<AuditLog> {
+        let path = with_query(&Endpoint::GuildAuditLog { guild_id }.path(), query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+}
@@ -0,0 +1,57 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::Endpoint;
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::auto_moderation::{
+    AutoModerationRule, CreateAutoModerationRule, ModifyAutoModerationRule,
+};
+use reqwest::Method;
+
+// All of these require `MANAGE_GUILD`.
+impl RestClient {
+    pub async fn list_auto_moderation_rules(&self, guild_id: Snowflake) -> Result<Vec<AutoModerationRule>> {
+        let path = Endpoint::AutoModerationRules { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_auto_moderation_rule(
+        &self,
+        guild_id: Snowflake,
+        rule_id: Snowflake,
+    ) -> Result<AutoModerationRule> {
+        let path = Endpoint::AutoModerationRule { guild_id, rule_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn create_auto_moderation_rule(
+        &self,
+        guild_id: Snowflake,
+        rule: &CreateAutoModerationRule,
+        reason: Option<&str>,
+    ) -> Result<AutoModerationRule> {
+        let path = Endpoint::AutoModerationRules { guild_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(rule)?), reason).await
+    }
+
+    pub async fn modify_auto_moderation_rule(
+        &self,
+        guild_id: Snowflake,
+        rule_id: Snowflake,
+        rule: &ModifyAutoModerationRule,
+        reason: Option<&str>,
+    ) -> Result<AutoModerationRule> {
+        let path = Endpoint::AutoModerationRule { guild_id, rule_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(rule)?), reason).await
+    }
+
+    pub async fn delete_auto_moderation_rule(
+        &self,
+        guild_id: Snowflake,
+        rule_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::AutoModerationRule { guild_id, rule_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::interaction::{
+    commands_differ, ApplicationCommand, CreateApplicationCommand, EditApplicationCommandPermissions,
+    GuildApplicationCommandPermissions,
+};
+use reqwest::Method;
+
+impl RestClient {
+    /// Includes all localizations, rather than only the bot's locale.
+    pub async fn get_global_application_commands(&self, application_id: Snowflake) -> Result<Vec<ApplicationCommand>> {
+        let path = with_query(
+            &Endpoint::GlobalCommands { application_id }.path(),
+            &serde_json::json!({ "with_localizations": true }),
+        )?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Creates the command, or replaces an existing one with the same name and type.
+    pub async fn create_global_application_command(
+        &self,
+        application_id: Snowflake,
+        command: &CreateApplicationCommand,
+    ) -> Result<ApplicationCommand> {
+        let path = Endpoint::GlobalCommands { application_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(command)?), None).await
+    }
+
+    pub async fn get_global_application_command(
+        &self,
+        application_id: Snowflake,
+        command_id: Snowflake,
+    ) -> Result<ApplicationCommand> {
+        let path = Endpoint::GlobalCommand { application_id, command_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn edit_global_application_command(
+        &self,
+        application_id: Snowflake,
+        command_id: Snowflake,
+        command: &CreateApplicationCommand,
+    ) -> Result<ApplicationCommand> {
+        let path = Endpoint::GlobalCommand { application_id, command_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(command)?), None).await
+    }
+
+    pub async fn delete_global_application_command(&self, application_id: Snowflake, command_id: Snowflake) -> Result<()> {
+        let path = Endpoint::GlobalCommand { application_id, command_id }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+
+    /// Replaces all global commands; commands not in `commands` are deleted.
+    pub async fn bulk_overwrite_global_application_commands(
+        &self,
+        application_id: Snowflake,
+        commands: &[CreateApplicationCommand],
+    ) -> Result<Vec<ApplicationCommand>> {
+        let path = Endpoint::GlobalCommands { application_id }.path();
+        self.request_typed(Method::PUT, &path, Some(to_body(&commands)?), None).await
+    }
+
+    /// Includes all localizations, rather than only the bot's locale.
+    pub async fn get_guild_application_commands(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+    ) -> Result<Vec<ApplicationCommand>> {
+        let path = with_query(
+            &Endpoint::GuildCommands { application_id, guild_id }.path(),
+            &serde_json::json!({ "with_localizations": true }),
+        )?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn create_guild_application_command(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        command: &CreateApplicationCommand,
+    ) -> Result<ApplicationCommand> {
+        let path = Endpoint::GuildCommands { application_id, guild_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(command)?), None).await
+    }
+
+    pub async fn get_guild_application_command(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        command_id: Snowflake,
+    ) -> Result<ApplicationCommand> {
+        let path = Endpoint::GuildCommand { application_id, guild_id, command_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn edit_guild_application_command(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        command_id: Snowflake,
+        command: &CreateApplicationCommand,
+    ) -> Result<ApplicationCommand> {
+        let path = Endpoint::GuildCommand { application_id, guild_id, command_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(command)?), None).await
+    }
+
+    pub async fn delete_guild_application_command(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        command_id: Snowflake,
+    ) -> Result<()> {
+        let path = Endpoint::GuildCommand { application_id, guild_id, command_id }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+
+    pub async fn bulk_overwrite_guild_application_commands(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        commands: &[CreateApplicationCommand],
+    ) -> Result<Vec<ApplicationCommand>> {
+        let path = Endpoint::GuildCommands { application_id, guild_id }.path();
+        self.request_typed(Method::PUT, &path, Some(to_body(&commands)?), None).await
+    }
+
+    /// Overwrites the global commands with `commands` unless they're already
+    /// registered that way. Returns whether an overwrite was sent.
+    pub async fn sync_global_application_commands(
+        &self,
+        application_id: Snowflake,
+        commands: &[CreateApplicationCommand],
+    ) -> Result<bool> {
+        let registered = self.get_global_application_commands(application_id).await?;
+        if !commands_differ(commands, &registered) {
+            return Ok(false);
+        }
+        self.bulk_overwrite_global_application_commands(application_id, commands).await?;
+        Ok(true)
+    }
+
+    /// Guild counterpart of `sync_global_application_commands`.
+    pub async fn sync_guild_application_commands(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        commands: &[CreateApplicationCommand],
+    ) -> Result<bool> {
+        let registered = self.get_guild_application_commands(application_id, guild_id).await?;
+        if !commands_differ(commands, &registered) {
+            return Ok(false);
+        }
+        self.bulk_overwrite_guild_application_commands(application_id, guild_id, commands).await?;
+        Ok(true)
+    }
+
+    /// Permissions of every command of the application in the guild that has any set.
+    pub async fn get_guild_application_command_permissions(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+    ) -> Result<Vec<GuildApplicationCommandPermissions>> {
+        let path = Endpoint::GuildCommandPermissions { application_id, guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_application_command_permissions(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        command_id: Snowflake,
+    ) -> Result<GuildApplicationCommandPermissions> {
+        let path = Endpoint::CommandPermissions { application_id, guild_id, command_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Requires a Bearer token with the `applications.commands.permissions.update`
+    /// scope; bot tokens are rejected.
+    pub async fn edit_application_command_permissions(
+        &self,
+        application_id: Snowflake,
+        guild_id: Snowflake,
+        command_id: Snowflake,
+        permissions: &EditApplicationCommandPermissions,
+    ) -> Result<GuildApplicationCommandPermissions> {
+        let path = Endpoint::CommandPermissions { application_id, guild_id, command_id }.path();
+        self.request_typed(Method::PUT, &path, Some(to_body(permissions)?), None).await
+    }
+}
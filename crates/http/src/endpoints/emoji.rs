@@ -0,0 +1,91 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::Endpoint;
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::emoji::{
+    ApplicationEmojis, CreateApplicationEmoji, CreateGuildEmoji, Emoji, ModifyGuildEmoji,
+};
+use reqwest::Method;
+use serde_json::json;
+
+impl RestClient {
+    pub async fn list_guild_emojis(&self, guild_id: Snowflake) -> Result<Vec<Emoji>> {
+        let path = Endpoint::GuildEmojis { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_guild_emoji(&self, guild_id: Snowflake, emoji_id: Snowflake) -> Result<Emoji> {
+        let path = Endpoint::GuildEmoji { guild_id, emoji_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn create_guild_emoji(
+        &self,
+        guild_id: Snowflake,
+        emoji: &CreateGuildEmoji,
+        reason: Option<&str>,
+    ) -> Result<Emoji> {
+        let path = Endpoint::GuildEmojis { guild_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(emoji)?), reason).await
+    }
+
+    pub async fn modify_guild_emoji(
+        &self,
+        guild_id: Snowflake,
+        emoji_id: Snowflake,
+        emoji: &ModifyGuildEmoji,
+        reason: Option<&str>,
+    ) -> Result<Emoji> {
+        let path = Endpoint::GuildEmoji { guild_id, emoji_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(emoji)?), reason).await
+    }
+
+    pub async fn delete_guild_emoji(
+        &self,
+        guild_id: Snowflake,
+        emoji_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildEmoji { guild_id, emoji_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+
+    /// Emojis owned by the application, usable by it in every guild.
+    pub async fn list_application_emojis(&self, application_id: Snowflake) -> Result<Vec<Emoji>> {
+        let path = Endpoint::ApplicationEmojis { application_id }.path();
+        let emojis: ApplicationEmojis = self.request_typed(Method::GET, &path, None, None).await?;
+        Ok(emojis.items)
+    }
+
+    pub async fn get_application_emoji(&self, application_id: Snowflake, emoji_id: Snowflake) -> Result<Emoji> {
+        let path = Endpoint::ApplicationEmoji { application_id, emoji_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn create_application_emoji(
+        &self,
+        application_id: Snowflake,
+        emoji: &CreateApplicationEmoji,
+    ) -> Result<Emoji> {
+        let path = Endpoint::ApplicationEmojis { application_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(emoji)?), None).await
+    }
+
+    /// Only the name of an application emoji can be changed.
+    pub async fn modify_application_emoji(
+        &self,
+        application_id: Snowflake,
+        emoji_id: Snowflake,
+        name: &str,
+    ) -> Result<Emoji> {
+        let path = Endpoint::ApplicationEmoji { application_id, emoji_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(json!({ "name": name })), None).await
+    }
+
+    pub async fn delete_application_emoji(&self, application_id: Snowflake, emoji_id: Snowflake) -> Result<()> {
+        let path = Endpoint::ApplicationEmoji { application_id, emoji_id }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::guild::{
+    BeginGuildPrune, CreateGuildChannel, GetGuildPruneCount, GuildPreview, GuildWidget,
+    GuildWidgetSettings, ModifyGuild, ModifyGuildChannelPosition, PruneCount, VanityUrl,
+};
+use discord_rs_model::{Channel, Guild};
+use reqwest::Method;
+
+impl RestClient {
+    /// Fetches a guild. `with_counts` fills in the approximate member and presence counts.
+    pub async fn get_guild(&self, guild_id: Snowflake, with_counts: bool) -> Result<Guild> {
+        let path = with_query(
+            &Endpoint::Guild { guild_id }.path(),
+            &serde_json::json!({ "with_counts": with_counts }),
+        )?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn modify_guild(
+        &self,
+        guild_id: Snowflake,
+        guild: &ModifyGuild,
+        reason: Option<&str>,
+    ) -> Result<Guild> {
+        let path = Endpoint::Guild { guild_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(guild)?), reason).await
+    }
+
+    pub async fn get_guild_preview(&self, guild_id: Snowflake) -> Result<GuildPreview> {
+        let path = Endpoint::GuildPreview { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_guild_channels(&self, guild_id: Snowflake) -> Result<Vec<Channel>> {
+        let path = Endpoint::GuildChannels { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn create_guild_channel(
+        &self,
+        guild_id: Snowflake,
+        channel: &CreateGuildChannel,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        let path = Endpoint::GuildChannels { guild_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(channel)?), reason).await
+    }
+
+    /// Reorders channels. Only the channels whose position or parent changes need to be sent.
+    pub async fn modify_guild_channel_positions(
+        &self,
+        guild_id: Snowflake,
+        positions: &[ModifyGuildChannelPosition],
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildChannels { guild_id }.path();
+        self.request(Method::PATCH, &path, Some(to_body(&positions)?), reason).await?;
+        Ok(())
+    }
+
+    /// Returns how many members a prune with the same parameters would kick.
+    pub async fn get_guild_prune_count(
+        &self,
+        guild_id: Snowflake,
+        query: &GetGuildPruneCount,
+    ) -> Result<PruneCount> {
+        let path = with_query(&Endpoint::GuildPrune { guild_id }.path(), query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn begin_guild_prune(
+        &self,
+        guild_id: Snowflake,
+        prune: &BeginGuildPrune,
+        reason: Option<&str>,
+    ) -> Result<PruneCount> {
+        let path = Endpoint::GuildPrune { guild_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(prune)?), reason).await
+    }
+
+    pub async fn get_guild_widget_settings(&self, guild_id: Snowflake) -> Result<GuildWidgetSettings> {
+        let path = Endpoint::GuildWidgetSettings { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn modify_guild_widget(
+        &self,
+        guild_id: Snowflake,
+        settings: &GuildWidgetSettings,
+        reason: Option<&str>,
+    ) -> Result<GuildWidgetSettings> {
+        let path = Endpoint::GuildWidgetSettings { guild_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(settings)?), reason).await
+    }
+
+    pub async fn get_guild_widget(&self, guild_id: Snowflake) -> Result<GuildWidget> {
+        let path = Endpoint::GuildWidget { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_guild_vanity_url(&self, guild_id: Snowflake) -> Result<VanityUrl> {
+        let path = Endpoint::GuildVanityUrl { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+}
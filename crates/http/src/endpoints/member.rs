@@ -0,0 +1,125 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use discord_rs_model::guild::{BulkBanResponse, GetGuildBans};
+use discord_rs_model::member::{ListGuildMembers, ModifyGuildMember};
+use discord_rs_model::{Ban, Member};
+use reqwest::Method;
+
+impl RestClient {
+    pub async fn get_guild_member(&self, guild_id: Snowflake, user_id: Snowflake) -> Result<Member> {
+        let path = Endpoint::GuildMember { guild_id, user_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Requires the `GUILD_MEMBERS` privileged intent.
+    pub async fn list_guild_members(&self, guild_id: Snowflake, query: &ListGuildMembers) -> Result<Vec<Member>> {
+        let path = with_query(&Endpoint::GuildMembers { guild_id }.path(), query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn modify_guild_member(
+        &self,
+        guild_id: Snowflake,
+        user_id: Snowflake,
+        member: &ModifyGuildMember,
+        reason: Option<&str>,
+    ) -> Result<Member> {
+        let path = Endpoint::GuildMember { guild_id, user_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(member)?), reason).await
+    }
+
+    pub async fn add_guild_member_role(
+        &self,
+        guild_id: Snowflake,
+        user_id: Snowflake,
+        role_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildMemberRole { guild_id, user_id, role_id }.path();
+        self.request(Method::PUT, &path, None, reason).await?;
+        Ok(())
+    }
+
+    pub async fn remove_guild_member_role(
+        &self,
+        guild_id: Snowflake,
+        user_id: Snowflake,
+        role_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildMemberRole { guild_id, user_id, role_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+
+    /// Kicks a member from the guild.
+    pub async fn remove_guild_member(
+        &self,
+        guild_id: Snowflake,
+        user_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildMember { guild_id, user_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+
+    pub async fn get_guild_bans(&self, guild_id: Snowflake, query: &GetGuildBans) -> Result<Vec<Ban>> {
+        let path = with_query(&Endpoint::GuildBans { guild_id }.path(), query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_guild_ban(&self, guild_id: Snowflake, user_id: Snowflake) -> Result<Ban> {
+        let path = Endpoint::GuildBan { guild_id, user_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Bans a user, optionally deleting up to 7 days (604800 seconds) of their messages.
+    pub async fn create_guild_ban(
+        &self,
+        guild_id: Snowflake,
+        user_id: Snowflake,
+        delete_message_seconds: Option<u32>,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildBan { guild_id, user_id }.path();
+        let body = delete_message_seconds
+            .map(|secs| serde_json::json!({ "delete_message_seconds": secs }));
+        self.request(Method::PUT, &path, body, reason).await?;
+        Ok(())
+    }
+
+    pub async fn remove_guild_ban(
+        &self,
+        guild_id: Snowflake,
+        user_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildBan { guild_id, user_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+
+    /// Bans up to 200 users at once.
+    pub async fn bulk_guild_ban(
+        &self,
+        guild_id: Snowflake,
+        user_ids: &[Snowflake],
+        delete_message_seconds: Option<u32>,
+        reason: Option<&str>,
+    ) -> Result<BulkBanResponse> {
+        if user_ids.is_empty() || user_ids.len() > 200 {
+            return Err(DiscordError::Validation(
+                "bulk ban requires between 1 and 200 user ids".to_string(),
+            ));
+        }
+        let path = Endpoint::GuildBulkBan { guild_id }.path();
+        let mut body = serde_json::json!({ "user_ids": user_ids });
+        if let Some(secs) = delete_message_seconds {
+            body["delete_message_seconds"] = secs.into();
+        }
+        self.request_typed(Method::POST, &path, Some(body), reason).await
+    }
+}
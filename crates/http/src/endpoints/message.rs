@@ -0,0 +1,71 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use discord_rs_model::message::GetMessages;
+use discord_rs_model::Message;
+use reqwest::Method;
+use serde::Serialize;
+
+impl RestClient {
+    pub async fn get_message(&self, channel_id: Snowflake, message_id: Snowflake) -> Result<Message> {
+        let path = Endpoint::ChannelMessage { channel_id, message_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_messages(&self, channel_id: Snowflake, query: &GetMessages) -> Result<Vec<Message>> {
+        let path = with_query(&Endpoint::ChannelMessages { channel_id }.path(), query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Posts a message. `message` is usually a `MessageBuilder`.
+    pub async fn create_message(&self, channel_id: Snowflake, message: &impl Serialize) -> Result<Message> {
+        let path = Endpoint::ChannelMessages { channel_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(message)?), None).await
+    }
+
+    pub async fn edit_message(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        message: &impl Serialize,
+    ) -> Result<Message> {
+        let path = Endpoint::ChannelMessage { channel_id, message_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(message)?), None).await
+    }
+
+    pub async fn delete_message(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::ChannelMessage { channel_id, message_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+
+    /// Deletes 2-100 messages at once. Messages older than two weeks are rejected by Discord.
+    pub async fn bulk_delete_messages(
+        &self,
+        channel_id: Snowflake,
+        message_ids: &[Snowflake],
+        reason: Option<&str>,
+    ) -> Result<()> {
+        if !(2..=100).contains(&message_ids.len()) {
+            return Err(DiscordError::Validation(
+                "bulk delete requires between 2 and 100 message ids".to_string(),
+            ));
+        }
+        let path = Endpoint::BulkDeleteMessages { channel_id }.path();
+        let body = serde_json::json!({ "messages": message_ids });
+        self.request(Method::POST, &path, Some(body), reason).await?;
+        Ok(())
+    }
+
+    /// Publishes a message in an announcement channel to following channels.
+    pub async fn crosspost_message(&self, channel_id: Snowflake, message_id: Snowflake) -> Result<Message> {
+        let path = Endpoint::CrosspostMessage { channel_id, message_id }.path();
+        self.request_typed(Method::POST, &path, None, None).await
+    }
+}
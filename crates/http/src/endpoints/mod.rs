@@ -0,0 +1,22 @@
+//! Typed endpoint groups. Each module adds an `impl RestClient` block.
+
+use discord_rs_core::{DiscordError, Result};
+use serde::Serialize;
+
+mod audit_log;
+mod auto_moderation;
+mod command;
+mod emoji;
+mod guild;
+mod member;
+mod message;
+mod reaction;
+mod role;
+mod sticker;
+mod thread;
+mod user;
+mod webhook;
+
+fn to_body(body: &impl Serialize) -> Result<serde_json::Value> {
+    serde_json::to_value(body).map_err(|e| DiscordError::Serialization(e.to_string()))
+}
@@ -0,0 +1,74 @@
+use crate::client::RestClient;
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::reaction::{GetReactions, ReactionType};
+use discord_rs_model::User;
+use reqwest::Method;
+
+impl RestClient {
+    pub async fn create_reaction(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        emoji: &ReactionType,
+    ) -> Result<()> {
+        let path = Endpoint::OwnReaction { channel_id, message_id, emoji }.path();
+        self.request(Method::PUT, &path, None, None).await?;
+        Ok(())
+    }
+
+    pub async fn delete_own_reaction(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        emoji: &ReactionType,
+    ) -> Result<()> {
+        let path = Endpoint::OwnReaction { channel_id, message_id, emoji }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+
+    /// Requires `MANAGE_MESSAGES`.
+    pub async fn delete_user_reaction(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        emoji: &ReactionType,
+        user_id: Snowflake,
+    ) -> Result<()> {
+        let path = Endpoint::UserReaction { channel_id, message_id, emoji, user_id }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+
+    /// One page of users who reacted; see `stream_reactions` for all of them.
+    pub async fn get_reactions(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        emoji: &ReactionType,
+        query: &GetReactions,
+    ) -> Result<Vec<User>> {
+        let path = with_query(&Endpoint::MessageReaction { channel_id, message_id, emoji }.path(), query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Requires `MANAGE_MESSAGES`.
+    pub async fn delete_all_reactions(&self, channel_id: Snowflake, message_id: Snowflake) -> Result<()> {
+        let path = Endpoint::MessageReactions { channel_id, message_id }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+
+    /// Requires `MANAGE_MESSAGES`.
+    pub async fn delete_all_reactions_for_emoji(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        emoji: &ReactionType,
+    ) -> Result<()> {
+        let path = Endpoint::MessageReaction { channel_id, message_id, emoji }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+}
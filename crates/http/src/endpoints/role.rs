@@ -0,0 +1,57 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::Endpoint;
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::role::{EditRole, ModifyRolePosition};
+use discord_rs_model::Role;
+use reqwest::Method;
+
+impl RestClient {
+    pub async fn get_guild_roles(&self, guild_id: Snowflake) -> Result<Vec<Role>> {
+        let path = Endpoint::GuildRoles { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn create_guild_role(
+        &self,
+        guild_id: Snowflake,
+        role: &EditRole,
+        reason: Option<&str>,
+    ) -> Result<Role> {
+        let path = Endpoint::GuildRoles { guild_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(role)?), reason).await
+    }
+
+    pub async fn modify_guild_role(
+        &self,
+        guild_id: Snowflake,
+        role_id: Snowflake,
+        role: &EditRole,
+        reason: Option<&str>,
+    ) -> Result<Role> {
+        let path = Endpoint::GuildRole { guild_id, role_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(role)?), reason).await
+    }
+
+    /// Reorders roles and returns the guild's full role list.
+    pub async fn modify_guild_role_positions(
+        &self,
+        guild_id: Snowflake,
+        positions: &[ModifyRolePosition],
+        reason: Option<&str>,
+    ) -> Result<Vec<Role>> {
+        let path = Endpoint::GuildRoles { guild_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(&positions)?), reason).await
+    }
+
+    pub async fn delete_guild_role(
+        &self,
+        guild_id: Snowflake,
+        role_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildRole { guild_id, role_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+}
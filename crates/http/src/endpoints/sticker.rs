@@ -0,0 +1,78 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::Endpoint;
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use discord_rs_model::sticker::{CreateGuildSticker, ModifyGuildSticker, Sticker, StickerPack, StickerPacks};
+use reqwest::multipart::{Form, Part};
+use reqwest::Method;
+
+impl RestClient {
+    pub async fn get_sticker(&self, sticker_id: Snowflake) -> Result<Sticker> {
+        let path = Endpoint::Sticker { sticker_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Discord's standard sticker packs.
+    pub async fn list_sticker_packs(&self) -> Result<Vec<StickerPack>> {
+        let packs: StickerPacks = self.request_typed(Method::GET, &Endpoint::StickerPacks.path(), None, None).await?;
+        Ok(packs.sticker_packs)
+    }
+
+    pub async fn list_guild_stickers(&self, guild_id: Snowflake) -> Result<Vec<Sticker>> {
+        let path = Endpoint::GuildStickers { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_guild_sticker(&self, guild_id: Snowflake, sticker_id: Snowflake) -> Result<Sticker> {
+        let path = Endpoint::GuildSticker { guild_id, sticker_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn create_guild_sticker(
+        &self,
+        guild_id: Snowflake,
+        sticker: &CreateGuildSticker,
+        reason: Option<&str>,
+    ) -> Result<Sticker> {
+        let path = Endpoint::GuildStickers { guild_id }.path();
+        let value = self
+            .request_multipart_retryable(Method::POST, &path, || sticker_form(sticker), reason)
+            .await?;
+        serde_json::from_value(value).map_err(|e| DiscordError::Serialization(e.to_string()))
+    }
+
+    pub async fn modify_guild_sticker(
+        &self,
+        guild_id: Snowflake,
+        sticker_id: Snowflake,
+        sticker: &ModifyGuildSticker,
+        reason: Option<&str>,
+    ) -> Result<Sticker> {
+        let path = Endpoint::GuildSticker { guild_id, sticker_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(sticker)?), reason).await
+    }
+
+    pub async fn delete_guild_sticker(
+        &self,
+        guild_id: Snowflake,
+        sticker_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::GuildSticker { guild_id, sticker_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+}
+
+/// Sticker uploads take plain form fields, not `payload_json`.
+fn sticker_form(sticker: &CreateGuildSticker) -> Result<Form> {
+    let mut file = Part::bytes(sticker.file.data.clone()).file_name(sticker.file.filename.clone());
+    if let Some(content_type) = &sticker.file.content_type {
+        file = file.mime_str(content_type).map_err(|e| DiscordError::Http(e.to_string()))?;
+    }
+    Ok(Form::new()
+        .text("name", sticker.name.clone())
+        .text("description", sticker.description.clone())
+        .text("tags", sticker.tags.clone())
+        .part("file", file))
+}
@@ -0,0 +1,114 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::channel::{
+    ArchivedThreadKind, GetThreadMembers, ListArchivedThreads, StartForumThread,
+    StartThreadFromMessage, StartThreadWithoutMessage, ThreadList, ThreadMember,
+};
+use discord_rs_model::Channel;
+use reqwest::Method;
+
+impl RestClient {
+    pub async fn start_thread_from_message(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        thread: &StartThreadFromMessage,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        let path = Endpoint::MessageThreads { channel_id, message_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(thread)?), reason).await
+    }
+
+    pub async fn start_thread_without_message(
+        &self,
+        channel_id: Snowflake,
+        thread: &StartThreadWithoutMessage,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        let path = Endpoint::ChannelThreads { channel_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(thread)?), reason).await
+    }
+
+    /// Creates a post in a forum or media channel.
+    pub async fn start_forum_thread(
+        &self,
+        channel_id: Snowflake,
+        thread: &StartForumThread,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        let path = Endpoint::ChannelThreads { channel_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(thread)?), reason).await
+    }
+
+    pub async fn join_thread(&self, thread_id: Snowflake) -> Result<()> {
+        let path = Endpoint::CurrentThreadMember { thread_id }.path();
+        self.request(Method::PUT, &path, None, None).await?;
+        Ok(())
+    }
+
+    pub async fn leave_thread(&self, thread_id: Snowflake) -> Result<()> {
+        let path = Endpoint::CurrentThreadMember { thread_id }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+
+    pub async fn add_thread_member(&self, thread_id: Snowflake, user_id: Snowflake) -> Result<()> {
+        let path = Endpoint::ThreadMember { thread_id, user_id }.path();
+        self.request(Method::PUT, &path, None, None).await?;
+        Ok(())
+    }
+
+    pub async fn remove_thread_member(&self, thread_id: Snowflake, user_id: Snowflake) -> Result<()> {
+        let path = Endpoint::ThreadMember { thread_id, user_id }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+
+    pub async fn get_thread_member(
+        &self,
+        thread_id: Snowflake,
+        user_id: Snowflake,
+        with_member: bool,
+    ) -> Result<ThreadMember> {
+        let path = with_query(
+            &Endpoint::ThreadMember { thread_id, user_id }.path(),
+            &serde_json::json!({ "with_member": with_member }),
+        )?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_thread_members(
+        &self,
+        thread_id: Snowflake,
+        query: &GetThreadMembers,
+    ) -> Result<Vec<ThreadMember>> {
+        let path = with_query(&Endpoint::ThreadMembers { thread_id }.path(), query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Lists all active threads in a guild the bot can see.
+    pub async fn get_active_guild_threads(&self, guild_id: Snowflake) -> Result<ThreadList> {
+        let path = Endpoint::GuildActiveThreads { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Fetches one page of archived threads. Use `ListArchivedThreads::next_page` to continue.
+    pub async fn get_archived_threads(
+        &self,
+        channel_id: Snowflake,
+        kind: ArchivedThreadKind,
+        query: &ListArchivedThreads,
+    ) -> Result<ThreadList> {
+        let path = match kind {
+            ArchivedThreadKind::Public => Endpoint::PublicArchivedThreads { channel_id }.path(),
+            ArchivedThreadKind::Private => Endpoint::PrivateArchivedThreads { channel_id }.path(),
+            ArchivedThreadKind::JoinedPrivate => {
+                Endpoint::JoinedPrivateArchivedThreads { channel_id }.path()
+            }
+        };
+        let path = with_query(&path, query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+}
@@ -0,0 +1,36 @@
+use crate::client::RestClient;
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::guild::PartialGuild;
+use discord_rs_model::user::GetCurrentUserGuilds;
+use discord_rs_model::{Member, User};
+use reqwest::Method;
+
+impl RestClient {
+    pub async fn get_current_user(&self) -> Result<User> {
+        self.request_typed(Method::GET, &Endpoint::CurrentUser.path(), None, None).await
+    }
+
+    pub async fn get_user(&self, user_id: Snowflake) -> Result<User> {
+        let path = Endpoint::User { user_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Guilds the current user is in. With a Bearer token this needs the `guilds` scope.
+    pub async fn get_current_user_guilds(&self, query: &GetCurrentUserGuilds) -> Result<Vec<PartialGuild>> {
+        let path = with_query(&Endpoint::CurrentUserGuilds.path(), query)?;
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Bearer only; needs the `guilds.members.read` scope.
+    pub async fn get_current_user_guild_member(&self, guild_id: Snowflake) -> Result<Member> {
+        let path = Endpoint::CurrentUserGuildMember { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn leave_guild(&self, guild_id: Snowflake) -> Result<()> {
+        let path = Endpoint::CurrentUserGuild { guild_id }.path();
+        self.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use super::to_body;
+use crate::client::RestClient;
+use crate::routing::Endpoint;
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::webhook::{
+    CreateWebhook, FollowAnnouncementChannel, FollowedChannel, ModifyWebhook, Webhook,
+};
+use reqwest::Method;
+
+impl RestClient {
+    pub async fn create_webhook(
+        &self,
+        channel_id: Snowflake,
+        webhook: &CreateWebhook,
+        reason: Option<&str>,
+    ) -> Result<Webhook> {
+        let path = Endpoint::ChannelWebhooks { channel_id }.path();
+        self.request_typed(Method::POST, &path, Some(to_body(webhook)?), reason).await
+    }
+
+    pub async fn get_channel_webhooks(&self, channel_id: Snowflake) -> Result<Vec<Webhook>> {
+        let path = Endpoint::ChannelWebhooks { channel_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_guild_webhooks(&self, guild_id: Snowflake) -> Result<Vec<Webhook>> {
+        let path = Endpoint::GuildWebhooks { guild_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn get_webhook(&self, webhook_id: Snowflake) -> Result<Webhook> {
+        let path = Endpoint::Webhook { webhook_id }.path();
+        self.request_typed(Method::GET, &path, None, None).await
+    }
+
+    /// Needs no permissions; the returned webhook has no `user`.
+    pub async fn get_webhook_with_token(&self, webhook_id: Snowflake, token: &str) -> Result<Webhook> {
+        let path = Endpoint::WebhookWithToken { webhook_id, token }.path();
+        self.without_authorization().request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn modify_webhook(
+        &self,
+        webhook_id: Snowflake,
+        webhook: &ModifyWebhook,
+        reason: Option<&str>,
+    ) -> Result<Webhook> {
+        let path = Endpoint::Webhook { webhook_id }.path();
+        self.request_typed(Method::PATCH, &path, Some(to_body(webhook)?), reason).await
+    }
+
+    /// Same as `modify_webhook`, except that `channel_id` can't be changed.
+    pub async fn modify_webhook_with_token(
+        &self,
+        webhook_id: Snowflake,
+        token: &str,
+        webhook: &ModifyWebhook,
+        reason: Option<&str>,
+    ) -> Result<Webhook> {
+        let path = Endpoint::WebhookWithToken { webhook_id, token }.path();
+        let body = ModifyWebhook {
+            channel_id: None,
+            ..webhook.clone()
+        };
+        self.without_authorization()
+            .request_typed(Method::PATCH, &path, Some(to_body(&body)?), reason)
+            .await
+    }
+
+    pub async fn delete_webhook(&self, webhook_id: Snowflake, reason: Option<&str>) -> Result<()> {
+        let path = Endpoint::Webhook { webhook_id }.path();
+        self.request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+
+    pub async fn delete_webhook_with_token(
+        &self,
+        webhook_id: Snowflake,
+        token: &str,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let path = Endpoint::WebhookWithToken { webhook_id, token }.path();
+        self.without_authorization().request(Method::DELETE, &path, None, reason).await?;
+        Ok(())
+    }
+
+    /// Crossposts messages of the announcement channel `channel_id` into
+    /// `webhook_channel_id` through a new channel follower webhook.
+    pub async fn follow_announcement_channel(
+        &self,
+        channel_id: Snowflake,
+        webhook_channel_id: Snowflake,
+        reason: Option<&str>,
+    ) -> Result<FollowedChannel> {
+        let path = Endpoint::ChannelFollowers { channel_id }.path();
+        let body = FollowAnnouncementChannel { webhook_channel_id };
+        self.request_typed(Method::POST, &path, Some(to_body(&body)?), reason).await
+    }
+}
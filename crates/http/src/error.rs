@@ -1,4 +1,4 @@
-use discord_rs_core::DiscordError;
+use discord_rs_core::{ApiError, DiscordError, FieldError, JsonErrorCode};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
@@ -8,6 +8,21 @@ pub struct DiscordApiError {
     pub errors: Option<serde_json::Value>,
 }
 
+impl DiscordApiError {
+    pub fn into_api_error(self, status: u16) -> ApiError {
+        let mut errors = Vec::new();
+        if let Some(tree) = &self.errors {
+            flatten_errors(tree, &mut Vec::new(), &mut errors);
+        }
+        ApiError {
+            status,
+            code: JsonErrorCode::from(self.code as u32),
+            message: self.message,
+            errors,
+        }
+    }
+}
+
 impl std::fmt::Display for DiscordApiError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Discord API Error {}: {}", self.code, self.message)?;
@@ -17,3 +32,45 @@ impl std::fmt::Display for DiscordApiError {
         Ok(())
     }
 }
+
+const MAX_BODY_SNIPPET: usize = 200;
+
+/// Builds the error for a non-success response body.
+///
+/// Only bodies in Discord's error format become `DiscordError::Api`; anything
+/// else (a proxy's HTML 502, a CDN 404) is a plain `DiscordError::Http` so
+/// `api_code()` never reports a code Discord didn't send.
+pub fn error_from_response(status: u16, body: &[u8]) -> DiscordError {
+    match serde_json::from_slice::<DiscordApiError>(body) {
+        Ok(api_error) => DiscordError::Api(api_error.into_api_error(status)),
+        Err(_) => {
+            let body = String::from_utf8_lossy(body);
+            let snippet: String = body.trim().chars().take(MAX_BODY_SNIPPET).collect();
+            DiscordError::Http(format!("Request failed with status {}: {}", status, snippet))
+        }
+    }
+}
+
+// Discord nests field errors by path, e.g.
+// {"embeds": {"0": {"title": {"_errors": [{"code": "...", "message": "..."}]}}}}
+fn flatten_errors<'a>(value: &'a serde_json::Value, path: &mut Vec<&'a str>, out: &mut Vec<FieldError>) {
+    let Some(map) = value.as_object() else {
+        return;
+    };
+
+    for (key, child) in map {
+        if key == "_errors" {
+            for error in child.as_array().into_iter().flatten() {
+                out.push(FieldError {
+                    path: path.join("."),
+                    code: error.get("code").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                    message: error.get("message").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                });
+            }
+        } else {
+            path.push(key);
+            flatten_errors(child, path, out);
+            path.pop();
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Tracking of invalid (401/403/429) responses.
+//!
+//! Discord bans an IP for a while once it makes 10,000 invalid requests in
+//! 10 minutes. The tracker keeps a sliding window of those responses so the
+//! client can stop sending well before that point.
+
+use discord_rs_core::{DiscordError, Result};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct InvalidRequestConfig {
+    /// Length of the sliding window.
+    pub window: Duration,
+    /// Invalid responses within `window` after which requests are rejected locally.
+    pub threshold: usize,
+    /// How many 429s a single request may hit before giving up.
+    pub max_rate_limit_retries: u32,
+}
+
+impl Default for InvalidRequestConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(600),
+            threshold: 8_000,
+            max_rate_limit_retries: 5,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidRequestTracker {
+    config: InvalidRequestConfig,
+    hits: Mutex<VecDeque<Instant>>,
+}
+
+impl InvalidRequestTracker {
+    pub fn new(config: InvalidRequestConfig) -> Self {
+        Self {
+            config,
+            hits: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn config(&self) -> &InvalidRequestConfig {
+        &self.config
+    }
+
+    /// Records one invalid response.
+    pub fn record(&self) {
+        let now = Instant::now();
+        let mut hits = self.hits.lock().unwrap();
+        Self::prune(&mut hits, now, self.config.window);
+        hits.push_back(now);
+
+        if hits.len() == self.config.threshold {
+            tracing::warn!(
+                "{} invalid requests in the last {:?}; rejecting requests locally",
+                hits.len(),
+                self.config.window
+            );
+        }
+    }
+
+    /// Number of invalid responses currently inside the window.
+    pub fn count(&self) -> usize {
+        let mut hits = self.hits.lock().unwrap();
+        Self::prune(&mut hits, Instant::now(), self.config.window);
+        hits.len()
+    }
+
+    /// Fails with `DiscordError::RateLimit` once the threshold is reached.
+    pub fn check(&self) -> Result<()> {
+        if self.count() >= self.config.threshold {
+            return Err(DiscordError::RateLimit);
+        }
+        Ok(())
+    }
+
+    fn prune(hits: &mut VecDeque<Instant>, now: Instant, window: Duration) {
+        while let Some(&oldest) = hits.front() {
+            if now.duration_since(oldest) < window {
+                break;
+            }
+            hits.pop_front();
+        }
+    }
+}
+
+impl Default for InvalidRequestTracker {
+    fn default() -> Self {
+        Self::new(InvalidRequestConfig::default())
+    }
+}
@@ -2,5 +2,13 @@ pub mod client;
 pub mod ratelimit;
 pub mod routing;
 pub mod error;
+pub mod invalid_requests;
+pub mod retry;
+pub mod pagination;
+pub mod oauth2;
+pub mod webhook;
+pub mod middleware;
+mod endpoints;
 
-pub use client::RestClient;
\ No newline at end of file
+pub use client::{RestClient, RestClientBuilder, TokenType};
+pub use webhook::WebhookClient;
\ No newline at end of file
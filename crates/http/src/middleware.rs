@@ -0,0 +1,93 @@
+//! Hooks run around every request `RestClient` sends, JSON or multipart.
+//!
+//! Hooks are called once per attempt, so a request retried after a 429 or
+//! a 5xx shows up several times. All methods have empty defaults.
+
+use crate::ratelimit::RateLimitScope;
+use crate::routing::Route;
+use discord_rs_core::DiscordError;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The request an attempt is made for.
+#[derive(Debug)]
+pub struct RequestInfo<'a> {
+    pub route: &'a Route<'a>,
+    /// 1 for the first attempt; 429 retries don't increase it.
+    pub attempt: u32,
+}
+
+#[derive(Debug)]
+pub struct ResponseInfo<'a> {
+    pub status: StatusCode,
+    pub headers: &'a HeaderMap,
+    /// Time from sending the request until the response headers arrived.
+    pub elapsed: Duration,
+}
+
+/// A 429, as parsed from its headers and body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitInfo {
+    pub retry_after: f64,
+    pub global: bool,
+    pub scope: Option<RateLimitScope>,
+}
+
+pub trait Middleware: Send + Sync {
+    /// Before each attempt. Headers added here are sent with the request.
+    fn before_send(&self, _request: &RequestInfo<'_>, _headers: &mut HeaderMap) {}
+
+    /// After each response, successful or not.
+    fn after_response(&self, _request: &RequestInfo<'_>, _response: &ResponseInfo<'_>) {}
+
+    /// After a 429, before the client waits it out.
+    fn on_ratelimit(&self, _request: &RequestInfo<'_>, _ratelimit: &RateLimitInfo) {}
+
+    /// When sending failed without a response (connection error, timeout).
+    fn on_error(&self, _request: &RequestInfo<'_>, _error: &DiscordError) {}
+}
+
+/// The middleware stack of a client, run in the order added.
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(Vec<Arc<dyn Middleware>>);
+
+impl Middlewares {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    pub(crate) fn before_send(&self, request: &RequestInfo<'_>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for m in &self.0 {
+            m.before_send(request, &mut headers);
+        }
+        headers
+    }
+
+    pub(crate) fn after_response(&self, request: &RequestInfo<'_>, response: &ResponseInfo<'_>) {
+        for m in &self.0 {
+            m.after_response(request, response);
+        }
+    }
+
+    pub(crate) fn on_ratelimit(&self, request: &RequestInfo<'_>, ratelimit: &RateLimitInfo) {
+        for m in &self.0 {
+            m.on_ratelimit(request, ratelimit);
+        }
+    }
+
+    pub(crate) fn on_error(&self, request: &RequestInfo<'_>, error: &DiscordError) {
+        for m in &self.0 {
+            m.on_error(request, error);
+        }
+    }
+}
+
+impl fmt::Debug for Middlewares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Middlewares({})", self.0.len())
+    }
+}
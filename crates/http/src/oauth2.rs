@@ -0,0 +1,244 @@
+//! OAuth2 flows against Discord's token endpoints.
+//!
+//! Tokens obtained here are used with `RestClient::bearer` to call endpoints
+//! on behalf of a user, e.g. `get_current_user_guilds`.
+
+use crate::client::{DEFAULT_API_VERSION, DEFAULT_BASE_URL, DEFAULT_TIMEOUT};
+use crate::error::error_from_response;
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use reqwest::Client as ReqwestClient;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub const AUTHORIZE_URL: &str = "https://discord.com/oauth2/authorize";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Scope {
+    ActivitiesRead,
+    ActivitiesWrite,
+    ApplicationsCommands,
+    ApplicationsCommandsUpdate,
+    ApplicationsCommandsPermissionsUpdate,
+    Bot,
+    Connections,
+    DmChannelsRead,
+    Email,
+    GdmJoin,
+    Guilds,
+    GuildsJoin,
+    GuildsMembersRead,
+    Identify,
+    MessagesRead,
+    RoleConnectionsWrite,
+    WebhookIncoming,
+    Other(String),
+}
+
+impl Scope {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Scope::ActivitiesRead => "activities.read",
+            Scope::ActivitiesWrite => "activities.write",
+            Scope::ApplicationsCommands => "applications.commands",
+            Scope::ApplicationsCommandsUpdate => "applications.commands.update",
+            Scope::ApplicationsCommandsPermissionsUpdate => "applications.commands.permissions.update",
+            Scope::Bot => "bot",
+            Scope::Connections => "connections",
+            Scope::DmChannelsRead => "dm_channels.read",
+            Scope::Email => "email",
+            Scope::GdmJoin => "gdm.join",
+            Scope::Guilds => "guilds",
+            Scope::GuildsJoin => "guilds.join",
+            Scope::GuildsMembersRead => "guilds.members.read",
+            Scope::Identify => "identify",
+            Scope::MessagesRead => "messages.read",
+            Scope::RoleConnectionsWrite => "role_connections.write",
+            Scope::WebhookIncoming => "webhook.incoming",
+            Scope::Other(s) => s,
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn join_scopes(scopes: &[Scope]) -> String {
+    scopes.iter().map(Scope::as_str).collect::<Vec<_>>().join(" ")
+}
+
+/// Response of the token endpoint. `Debug` leaves out the tokens.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AccessToken {
+    pub access_token: String,
+    pub token_type: String,
+    /// Lifetime in seconds.
+    pub expires_in: u64,
+    /// Not returned for the client credentials grant.
+    pub refresh_token: Option<String>,
+    pub scope: String,
+}
+
+impl AccessToken {
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scope.split_whitespace()
+    }
+}
+
+impl fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("access_token", &"<redacted>")
+            .field("token_type", &self.token_type)
+            .field("expires_in", &self.expires_in)
+            .field("refresh_token", &self.refresh_token.as_ref().map(|_| "<redacted>"))
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OAuthErrorBody {
+    error: String,
+    error_description: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct OAuth2Client {
+    http: ReqwestClient,
+    client_id: Snowflake,
+    client_secret: String,
+    redirect_uri: Option<String>,
+    api_base: String,
+}
+
+impl fmt::Debug for OAuth2Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2Client")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"<redacted>")
+            .field("redirect_uri", &self.redirect_uri)
+            .field("api_base", &self.api_base)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuth2Client {
+    pub fn new(client_id: Snowflake, client_secret: impl Into<String>) -> Self {
+        Self {
+            http: ReqwestClient::new(),
+            client_id,
+            client_secret: client_secret.into(),
+            redirect_uri: None,
+            api_base: format!("{}/v{}", DEFAULT_BASE_URL, DEFAULT_API_VERSION),
+        }
+    }
+
+    /// Must match one of the redirects registered for the application.
+    pub fn redirect_uri(mut self, uri: impl Into<String>) -> Self {
+        self.redirect_uri = Some(uri.into());
+        self
+    }
+
+    /// API base the token endpoints live under, including the version
+    /// segment. Mainly for pointing at a stand-in server.
+    pub fn api_base(mut self, base: impl Into<String>) -> Self {
+        self.api_base = base.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn http_client(mut self, client: ReqwestClient) -> Self {
+        self.http = client;
+        self
+    }
+
+    /// URL to send the user to for the authorization code grant. `state`
+    /// should be an unguessable value checked again on the redirect.
+    pub fn authorization_url(&self, scopes: &[Scope], state: Option<&str>) -> Result<String> {
+        let redirect_uri = self.redirect_uri.as_deref().ok_or_else(|| {
+            DiscordError::Configuration("redirect_uri required for the authorization code grant".to_string())
+        })?;
+
+        let mut url = url::Url::parse(AUTHORIZE_URL).map_err(|e| DiscordError::Validation(e.to_string()))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &self.client_id.to_string())
+                .append_pair("scope", &join_scopes(scopes))
+                .append_pair("redirect_uri", redirect_uri);
+            if let Some(state) = state {
+                query.append_pair("state", state);
+            }
+        }
+        Ok(url.to_string())
+    }
+
+    /// Exchanges the `code` from the redirect for an access token.
+    pub async fn exchange_code(&self, code: &str) -> Result<AccessToken> {
+        let redirect_uri = self.redirect_uri.as_deref().ok_or_else(|| {
+            DiscordError::Configuration("redirect_uri required for the authorization code grant".to_string())
+        })?;
+        self.token_request(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+        ])
+        .await
+    }
+
+    pub async fn refresh(&self, refresh_token: &str) -> Result<AccessToken> {
+        self.token_request(&[("grant_type", "refresh_token"), ("refresh_token", refresh_token)])
+            .await
+    }
+
+    /// Token for the application owner, without a user redirect.
+    pub async fn client_credentials(&self, scopes: &[Scope]) -> Result<AccessToken> {
+        let scope = join_scopes(scopes);
+        self.token_request(&[("grant_type", "client_credentials"), ("scope", &scope)])
+            .await
+    }
+
+    /// Revokes an access or refresh token.
+    pub async fn revoke(&self, token: &str) -> Result<()> {
+        self.post_form("/oauth2/token/revoke", &[("token", token)]).await?;
+        Ok(())
+    }
+
+    async fn token_request(&self, form: &[(&str, &str)]) -> Result<AccessToken> {
+        let bytes = self.post_form("/oauth2/token", form).await?;
+        serde_json::from_slice(&bytes).map_err(|e| DiscordError::Serialization(e.to_string()))
+    }
+
+    async fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Vec<u8>> {
+        let response = self
+            .http
+            .post(format!("{}{}", self.api_base, path))
+            .basic_auth(self.client_id, Some(&self.client_secret))
+            .form(form)
+            .timeout(DEFAULT_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| DiscordError::Http(e.to_string()))?;
+
+        let status = response.status();
+        let bytes = response.bytes().await.map_err(|e| DiscordError::Http(e.to_string()))?;
+        if status.is_success() {
+            return Ok(bytes.to_vec());
+        }
+        if status.as_u16() == 429 {
+            return Err(DiscordError::RateLimit);
+        }
+
+        // Token endpoints answer with RFC 6749 errors rather than Discord's usual body.
+        match serde_json::from_slice::<OAuthErrorBody>(&bytes) {
+            Ok(body) => Err(DiscordError::OAuth2 {
+                error: body.error,
+                description: body.error_description,
+            }),
+            Err(_) => Err(error_from_response(status.as_u16(), &bytes)),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! Lazy `Stream`s over Discord's cursor-paginated list endpoints.
+//!
+//! Pages are only requested as the stream is polled, so dropping the stream
+//! (or using `StreamExt::take`/`take_while`) stops fetching. Requests go
+//! through `RestClient::request` and wait on rate limits like any other.
+
+use crate::client::RestClient;
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::audit_log::{AuditLogEntry, GetGuildAuditLog};
+use discord_rs_model::channel::{ArchivedThreadKind, ListArchivedThreads};
+use discord_rs_model::guild::GetGuildBans;
+use discord_rs_model::member::ListGuildMembers;
+use discord_rs_model::message::GetMessages;
+use discord_rs_model::reaction::{GetReactions, ReactionKind, ReactionType};
+use discord_rs_model::{Ban, Channel, Member, Message, User};
+use futures::stream::{self, Stream, StreamExt};
+use std::future::Future;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Newest first, paging with `before`.
+    #[default]
+    Backward,
+    /// Oldest first, paging with `after`.
+    Forward,
+}
+
+/// Where a paginated stream starts, which way it walks and when it stops.
+#[derive(Debug, Clone, Default)]
+pub struct Pagination {
+    pub direction: Direction,
+    /// Exclusive cursor. `None` starts at the newest (backward) or oldest
+    /// (forward) item.
+    pub start: Option<Snowflake>,
+    /// Stop after this many items. Also shrinks the last page request.
+    pub max_items: Option<usize>,
+}
+
+impl Pagination {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Items older than `id`, newest first.
+    pub fn before(id: Snowflake) -> Self {
+        Self {
+            direction: Direction::Backward,
+            start: Some(id),
+            max_items: None,
+        }
+    }
+
+    /// Items newer than `id`, oldest first.
+    pub fn after(id: Snowflake) -> Self {
+        Self {
+            direction: Direction::Forward,
+            start: Some(id),
+            max_items: None,
+        }
+    }
+
+    /// All items, oldest first.
+    pub fn oldest_first() -> Self {
+        Self {
+            direction: Direction::Forward,
+            ..Self::default()
+        }
+    }
+
+    pub fn limit(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+struct State<F> {
+    fetch: F,
+    cursor: Option<Snowflake>,
+    remaining: Option<usize>,
+    done: bool,
+}
+
+/// Drives a snowflake-cursor endpoint. `fetch` gets the direction, cursor
+/// and page size and returns one page.
+fn paginate<'a, T, F, Fut>(
+    pagination: Pagination,
+    page_size: usize,
+    id_of: fn(&T) -> Option<Snowflake>,
+    fetch: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: FnMut(Direction, Option<Snowflake>, usize) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + 'a,
+{
+    let direction = pagination.direction;
+    let cursor = match direction {
+        Direction::Forward => Some(pagination.start.unwrap_or(Snowflake::new(0))),
+        Direction::Backward => pagination.start,
+    };
+    let state = State {
+        fetch,
+        cursor,
+        remaining: pagination.max_items,
+        done: false,
+    };
+
+    stream::unfold(state, move |mut state| async move {
+        if state.done || state.remaining == Some(0) {
+            return None;
+        }
+
+        let limit = state.remaining.map_or(page_size, |r| r.min(page_size));
+        let mut page = match (state.fetch)(direction, state.cursor, limit).await {
+            Ok(page) => page,
+            Err(e) => {
+                state.done = true;
+                return Some((vec![Err(e)], state));
+            }
+        };
+        if page.is_empty() {
+            return None;
+        }
+
+        // Discord doesn't order every endpoint the same way.
+        page.sort_by_key(id_of);
+        if direction == Direction::Backward {
+            page.reverse();
+        }
+        page.truncate(limit);
+
+        let next = page.last().and_then(id_of);
+        state.done = page.len() < limit || next.is_none();
+        state.cursor = next.or(state.cursor);
+        state.remaining = state.remaining.map(|r| r - page.len());
+
+        Some((page.into_iter().map(Ok).collect(), state))
+    })
+    .flat_map(stream::iter)
+}
+
+impl RestClient {
+    /// Channel history, 100 messages per request.
+    pub fn stream_messages(
+        &self,
+        channel_id: Snowflake,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<Message>> + '_ {
+        paginate(pagination, 100, |m: &Message| Some(m.id), move |direction, cursor, limit| {
+            let mut query = GetMessages::new().limit(limit as u8);
+            match (direction, cursor) {
+                (Direction::Backward, Some(id)) => query = query.before(id),
+                (Direction::Forward, Some(id)) => query = query.after(id),
+                (_, None) => {}
+            }
+            async move { self.get_messages(channel_id, &query).await }
+        })
+    }
+
+    /// Guild members, 1000 per request. Discord only lists members in
+    /// ascending id order, so `direction` is ignored. Requires the
+    /// `GUILD_MEMBERS` privileged intent.
+    pub fn stream_guild_members(
+        &self,
+        guild_id: Snowflake,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<Member>> + '_ {
+        let pagination = Pagination {
+            direction: Direction::Forward,
+            ..pagination
+        };
+        paginate(pagination, 1000, |m: &Member| m.user.as_ref().map(|u| u.id), move |_, after, limit| {
+            let query = ListGuildMembers {
+                limit: Some(limit as u16),
+                after,
+            };
+            async move { self.list_guild_members(guild_id, &query).await }
+        })
+    }
+
+    /// Users who reacted with `emoji`, 100 per request. Like members, these
+    /// are only listed in ascending id order, so `direction` is ignored.
+    pub fn stream_reactions(
+        &self,
+        channel_id: Snowflake,
+        message_id: Snowflake,
+        emoji: ReactionType,
+        kind: ReactionKind,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<User>> + '_ {
+        let pagination = Pagination {
+            direction: Direction::Forward,
+            ..pagination
+        };
+        paginate(pagination, 100, |u: &User| Some(u.id), move |_, after, limit| {
+            let emoji = emoji.clone();
+            let query = GetReactions {
+                kind: Some(kind),
+                after,
+                limit: Some(limit as u8),
+            };
+            async move { self.get_reactions(channel_id, message_id, &emoji, &query).await }
+        })
+    }
+
+    /// Guild bans, 1000 per request.
+    pub fn stream_guild_bans(
+        &self,
+        guild_id: Snowflake,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<Ban>> + '_ {
+        paginate(pagination, 1000, |b: &Ban| Some(b.user.id), move |direction, cursor, limit| {
+            let mut query = GetGuildBans {
+                limit: Some(limit as u16),
+                ..Default::default()
+            };
+            match direction {
+                Direction::Backward => query.before = cursor,
+                Direction::Forward => query.after = cursor,
+            }
+            async move { self.get_guild_bans(guild_id, &query).await }
+        })
+    }
+
+    /// Audit log entries, 100 per request. `filter`'s `user_id` and
+    /// `action_type` narrow the entries; its cursors and limit are replaced
+    /// by `pagination`. Only the entries are yielded; fetch a page with
+    /// `get_guild_audit_log` when the referenced users and webhooks are needed.
+    pub fn stream_guild_audit_log(
+        &self,
+        guild_id: Snowflake,
+        filter: GetGuildAuditLog,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<AuditLogEntry>> + '_ {
+        paginate(pagination, 100, |e: &AuditLogEntry| Some(e.id), move |direction, cursor, limit| {
+            let mut query = GetGuildAuditLog {
+                before: None,
+                after: None,
+                limit: Some(limit as u8),
+                ..filter.clone()
+            };
+            match direction {
+                Direction::Backward => query.before = cursor,
+                Direction::Forward => query.after = cursor,
+            }
+            async move { self.get_guild_audit_log(guild_id, &query).await.map(|log| log.audit_log_entries) }
+        })
+    }
+
+    /// Archived threads, newest archived first. These page by archive
+    /// timestamp rather than id, so only `max_items` applies.
+    pub fn stream_archived_threads(
+        &self,
+        channel_id: Snowflake,
+        kind: ArchivedThreadKind,
+        max_items: Option<usize>,
+    ) -> impl Stream<Item = Result<Channel>> + '_ {
+        let page_size = |remaining: Option<usize>| Some(remaining.map_or(100, |r| r.min(100)) as u8);
+        let query = ListArchivedThreads {
+            before: None,
+            limit: page_size(max_items),
+        };
+
+        stream::unfold(Some((query, max_items)), move |state| async move {
+            let (query, remaining) = state?;
+            if remaining == Some(0) {
+                return None;
+            }
+            match self.get_archived_threads(channel_id, kind, &query).await {
+                Ok(page) => {
+                    let remaining = remaining.map(|r| r.saturating_sub(page.threads.len()));
+                    let next = query.next_page(kind, &page).map(|next| ListArchivedThreads {
+                        limit: page_size(remaining),
+                        ..next
+                    });
+                    let threads: Vec<Result<Channel>> = page.threads.into_iter().map(Ok).collect();
+                    Some((threads, next.map(|next| (next, remaining))))
+                }
+                Err(e) => Some((vec![Err(e)], None)),
+            }
+        })
+        .flat_map(stream::iter)
+        .take(max_items.unwrap_or(usize::MAX))
+    }
+}
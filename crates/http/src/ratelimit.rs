@@ -1,8 +1,12 @@
+pub mod service;
+
 use crate::routing::Route;
+use async_trait::async_trait;
 use dashmap::DashMap;
 use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::Mutex;
 use tokio::time::{sleep, Duration, Instant};
 use tracing::{debug, warn};
@@ -31,28 +35,150 @@ impl Bucket {
     }
 }
 
+/// The `X-RateLimit-Scope` of a 429 response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitScope {
+    /// Per-route limit for this bot.
+    User,
+    /// The bot-wide global limit.
+    Global,
+    /// A per-resource limit shared by everyone; doesn't count as an invalid request.
+    Shared,
+}
+
+impl RateLimitScope {
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        match headers.get("x-ratelimit-scope").and_then(|v| v.to_str().ok()) {
+            Some("user") => Some(RateLimitScope::User),
+            Some("global") => Some(RateLimitScope::Global),
+            Some("shared") => Some(RateLimitScope::Shared),
+            _ => None,
+        }
+    }
+}
+
+/// What the limiter tracks a route by: its template and major parameter, with ids
+/// and tokens already normalised or hashed away. Safe to log or send to a
+/// `service::RateLimitService`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteKey {
+    pub template: String,
+    pub major: String,
+}
+
+impl RouteKey {
+    /// The key for `route` as of the wall clock.
+    pub fn new(route: &Route<'_>) -> Self {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        Self::at(route, now_ms)
+    }
+
+    /// The key for `route` as of `now_ms` (Unix milliseconds), which decides whether a
+    /// message delete falls into the old-message bucket.
+    pub fn at(route: &Route<'_>, now_ms: u64) -> Self {
+        Self {
+            template: route.template_at(now_ms),
+            major: route.major_parameter().to_string(),
+        }
+    }
+
+    /// Same as `Route::bucket_key_at`.
+    pub fn bucket_key(&self) -> String {
+        if self.major.is_empty() {
+            self.template.clone()
+        } else {
+            format!("{}@{}", self.template, self.major)
+        }
+    }
+}
+
+/// Storage for rate limit state, so it can live outside the process.
+///
+/// `RestClient` calls `acquire` before every request and `update` with every
+/// response's headers; the `*_limited` methods are called after a 429.
+/// `RateLimiter` is the in-memory default; `service::RemoteRateLimiter`
+/// shares state between processes.
+#[async_trait]
+pub trait RateLimitBackend: std::fmt::Debug + Send + Sync {
+    /// Waits until a request on `route` may be sent, and claims a slot.
+    async fn acquire(&self, route: &Route<'_>);
+    /// Records the `X-RateLimit-*` headers of a response on `route`.
+    async fn update(&self, route: &Route<'_>, headers: &HeaderMap);
+    /// A non-global 429; the route's bucket is exhausted for `retry_after` seconds.
+    async fn bucket_limited(&self, route: &Route<'_>, retry_after: f64);
+    /// A global 429; returns once `retry_after` seconds have passed.
+    async fn global_limited(&self, retry_after: f64);
+}
+
+/// Tracks Discord's rate limit buckets.
+///
+/// Discord identifies buckets by the opaque `X-RateLimit-Bucket` hash; several routes may
+/// share one hash, and the limit applies per hash *and* major parameter. Until a route's
+/// hash has been seen it is tracked under its own route key.
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
+    // route template -> X-RateLimit-Bucket hash
+    hashes: Arc<DashMap<String, String>>,
+    // "{hash}:{major parameter}" (or the route key while the hash is unknown) -> bucket
     buckets: Arc<DashMap<String, Arc<Bucket>>>,
     global_lock: Arc<Mutex<()>>,
 }
 
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RateLimiter {
     pub fn new() -> Self {
         Self {
+            hashes: Arc::new(DashMap::new()),
             buckets: Arc::new(DashMap::new()),
             global_lock: Arc::new(Mutex::new(())),
         }
     }
 
+    /// The bucket hash Discord reported for this route, if any response has been seen yet.
+    pub fn bucket_hash(&self, route: &Route<'_>) -> Option<String> {
+        self.hashes.get(&RouteKey::new(route).template).map(|h| h.value().clone())
+    }
+
+    fn bucket_id(&self, key: &RouteKey) -> String {
+        match self.hashes.get(&key.template) {
+            Some(hash) => format!("{}:{}", hash.value(), key.major),
+            None => key.bucket_key(),
+        }
+    }
+
+    fn bucket(&self, key: &RouteKey) -> Arc<Bucket> {
+        self.buckets
+            .entry(self.bucket_id(key))
+            .or_insert_with(|| Arc::new(Bucket::new()))
+            .clone()
+    }
+
+    /// Remaining requests in the route's bucket as of the last response, if known.
+    pub async fn remaining(&self, route: &Route<'_>) -> Option<i64> {
+        let bucket = self.buckets.get(&self.bucket_id(&RouteKey::new(route)))?.clone();
+        let state = bucket.state.lock().await;
+        state.remaining
+    }
+
     pub async fn await_bucket(&self, route: &Route<'_>) {
+        self.await_key(&RouteKey::new(route)).await
+    }
+
+    pub(crate) async fn await_key(&self, key: &RouteKey) {
         // 1. Check global lock (wait if locked)
         {
             let _g = self.global_lock.lock().await;
         }
 
-        let key = route.bucket_key();
-        let bucket = self.buckets.entry(key).or_insert_with(|| Arc::new(Bucket::new())).clone();
+        let bucket = self.bucket(key);
 
         // 2. Lock the bucket queue
         let _guard = bucket.queue.lock().await;
@@ -66,10 +192,14 @@ impl RateLimiter {
                 if let Some(remaining) = state.remaining {
                      if remaining <= 0 {
                          let diff = reset_at - now;
-                         debug!("Bucket exhausted for {}, sleeping {:?}", route.path, diff);
+                         debug!("Bucket exhausted for {}, sleeping {:?}", key.bucket_key(), diff);
                          sleep(diff).await;
                      }
                 }
+            } else {
+                // The window has passed; the next response will tell us the new state.
+                state.remaining = state.limit;
+                state.reset_at = None;
             }
         }
         
@@ -89,12 +219,20 @@ impl RateLimiter {
     }
 
     pub async fn update(&self, route: &Route<'_>, headers: &HeaderMap) {
+        self.update_key(&RouteKey::new(route), headers).await
+    }
+
+    pub(crate) async fn update_key(&self, key: &RouteKey, headers: &HeaderMap) {
         // Parse headers
+        // X-RateLimit-Bucket (opaque hash shared by routes in the same bucket)
         // X-RateLimit-Limit
         // X-RateLimit-Remaining
         // X-RateLimit-Reset-After (seconds)
-        // X-RateLimit-Reset (epoch seconds)
         
+        let hash = headers.get("x-ratelimit-bucket")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
         let remaining = headers.get("x-ratelimit-remaining")
             .and_then(|v| v.to_str().ok())
             .and_then(|s| s.parse::<i64>().ok());
@@ -107,22 +245,40 @@ impl RateLimiter {
             .and_then(|v| v.to_str().ok())
             .and_then(|s| s.parse::<f64>().ok());
 
+        if let Some(hash) = hash {
+            let changed = self.hashes.get(&key.template).map(|h| *h.value() != hash).unwrap_or(true);
+            if changed {
+                debug!("Route {} mapped to bucket {}", key.template, hash);
+                self.hashes.insert(key.template.clone(), hash);
+            }
+        }
+
         if remaining.is_none() {
             return;
         }
 
-        let key = route.bucket_key();
-        if let Some(bucket) = self.buckets.get(&key) {
-            let mut state = bucket.state.lock().await;
-            state.remaining = remaining;
-            state.limit = limit;
-            
-            if let Some(secs) = reset_after {
-                state.reset_at = Some(Instant::now() + Duration::from_secs_f64(secs));
-            }
+        let bucket = self.bucket(key);
+        let mut state = bucket.state.lock().await;
+        state.remaining = remaining;
+        state.limit = limit;
+
+        if let Some(secs) = reset_after {
+            state.reset_at = Some(Instant::now() + Duration::from_secs_f64(secs));
         }
     }
 
+    /// Marks the route's bucket as exhausted for `retry_after` seconds after a non-global 429.
+    pub async fn handle_bucket_limit(&self, route: &Route<'_>, retry_after: f64) {
+        self.bucket_limit_key(&RouteKey::new(route), retry_after).await
+    }
+
+    pub(crate) async fn bucket_limit_key(&self, key: &RouteKey, retry_after: f64) {
+        let bucket = self.bucket(key);
+        let mut state = bucket.state.lock().await;
+        state.remaining = Some(0);
+        state.reset_at = Some(Instant::now() + Duration::from_secs_f64(retry_after));
+    }
+
     pub async fn handle_global_limit(&self, retry_after: f64) {
         warn!("Global rate limit hit! Sleeping for {}s", retry_after);
         let guard = self.global_lock.lock().await;
@@ -130,3 +286,22 @@ impl RateLimiter {
         drop(guard);
     }
 }
+
+#[async_trait]
+impl RateLimitBackend for RateLimiter {
+    async fn acquire(&self, route: &Route<'_>) {
+        self.await_bucket(route).await
+    }
+
+    async fn update(&self, route: &Route<'_>, headers: &HeaderMap) {
+        RateLimiter::update(self, route, headers).await
+    }
+
+    async fn bucket_limited(&self, route: &Route<'_>, retry_after: f64) {
+        self.handle_bucket_limit(route, retry_after).await
+    }
+
+    async fn global_limited(&self, retry_after: f64) {
+        self.handle_global_limit(retry_after).await
+    }
+}
@@ -0,0 +1,233 @@
+//! Rate limit state shared between processes through a small local service.
+//!
+//! Run one `RateLimitService` per bot token, and build every process's
+//! `RestClient` with a `RemoteRateLimiter` pointing at it:
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! use discord_rs_http::ratelimit::service::{RateLimitService, RemoteRateLimiter};
+//! use std::sync::Arc;
+//!
+//! // In the coordinating process:
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:7878").await?;
+//! tokio::spawn(RateLimitService::new().serve(listener));
+//!
+//! // In every worker:
+//! # let config = Arc::new(discord_rs_core::Config::new("token"));
+//! let rest = discord_rs_http::RestClient::builder(config)
+//!     .ratelimiter(Arc::new(RemoteRateLimiter::new("127.0.0.1:7878")))
+//!     .build();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The protocol is newline-delimited JSON: each request line is answered
+//! with `{"ok":true}` once the operation has completed, so an `acquire`
+//! response only arrives when the request may be sent. Routes travel as a
+//! `RouteKey`, so ids beyond the major parameter, query strings and webhook
+//! tokens never leave the process.
+
+use super::{RateLimitBackend, RateLimiter, RouteKey};
+use crate::routing::Route;
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Request {
+    Acquire {
+        route: RouteKey,
+    },
+    Update {
+        route: RouteKey,
+        headers: Vec<(String, String)>,
+    },
+    BucketLimited {
+        route: RouteKey,
+        retry_after: f64,
+    },
+    GlobalLimited {
+        retry_after: f64,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Response {
+    ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Holds the shared state. Every connection may send any number of requests.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitService {
+    limiter: RateLimiter,
+}
+
+impl RateLimitService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accepts connections until the listener fails.
+    pub async fn serve(self, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            debug!("Rate limit client connected from {}", peer);
+            let limiter = self.limiter.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(limiter, stream).await {
+                    debug!("Rate limit client {} disconnected: {}", peer, e);
+                }
+            });
+        }
+    }
+}
+
+async fn handle_connection(limiter: RateLimiter, stream: TcpStream) -> io::Result<()> {
+    let (read, mut write) = stream.into_split();
+    let mut lines = BufReader::new(read).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => match dispatch(&limiter, request).await {
+                Ok(()) => Response { ok: true, error: None },
+                Err(e) => Response { ok: false, error: Some(e) },
+            },
+            Err(e) => Response { ok: false, error: Some(e.to_string()) },
+        };
+        let mut out = serde_json::to_vec(&response).map_err(io::Error::other)?;
+        out.push(b'\n');
+        write.write_all(&out).await?;
+    }
+    Ok(())
+}
+
+async fn dispatch(limiter: &RateLimiter, request: Request) -> Result<(), String> {
+    match request {
+        Request::Acquire { route } => {
+            limiter.await_key(&route).await;
+        }
+        Request::Update { route, headers } => {
+            let mut map = HeaderMap::new();
+            for (name, value) in headers {
+                let name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| e.to_string())?;
+                let value = HeaderValue::from_str(&value).map_err(|e| e.to_string())?;
+                map.insert(name, value);
+            }
+            limiter.update_key(&route, &map).await;
+        }
+        Request::BucketLimited { route, retry_after } => {
+            limiter.bucket_limit_key(&route, retry_after).await;
+        }
+        Request::GlobalLimited { retry_after } => {
+            limiter.handle_global_limit(retry_after).await;
+        }
+    }
+    Ok(())
+}
+
+/// `RateLimitBackend` that asks a `RateLimitService`. When the service can't
+/// be reached it falls back to a process-local `RateLimiter`, so requests
+/// keep flowing (with only per-process limiting) instead of failing.
+#[derive(Debug)]
+pub struct RemoteRateLimiter {
+    addr: String,
+    // Idle connections; one is taken per call so concurrent waits don't block each other.
+    idle: Mutex<Vec<BufReader<TcpStream>>>,
+    fallback: RateLimiter,
+}
+
+impl RemoteRateLimiter {
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self {
+            addr: addr.into(),
+            idle: Mutex::new(Vec::new()),
+            fallback: RateLimiter::new(),
+        }
+    }
+
+    /// The process-local limiter used while the service is unreachable.
+    pub fn fallback(&self) -> &RateLimiter {
+        &self.fallback
+    }
+
+    async fn connection(&self) -> io::Result<BufReader<TcpStream>> {
+        if let Some(conn) = self.idle.lock().await.pop() {
+            return Ok(conn);
+        }
+        let stream = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&self.addr))
+            .await
+            .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "connect timed out"))??;
+        stream.set_nodelay(true)?;
+        Ok(BufReader::new(stream))
+    }
+
+    async fn call(&self, request: &Request) -> io::Result<()> {
+        let mut conn = self.connection().await?;
+
+        let mut line = serde_json::to_vec(request).map_err(io::Error::other)?;
+        line.push(b'\n');
+        conn.get_mut().write_all(&line).await?;
+
+        let mut reply = String::new();
+        if conn.read_line(&mut reply).await? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "service closed the connection"));
+        }
+        let response: Response = serde_json::from_str(&reply).map_err(io::Error::other)?;
+
+        self.idle.lock().await.push(conn);
+        if response.ok {
+            Ok(())
+        } else {
+            Err(io::Error::other(response.error.unwrap_or_default()))
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimitBackend for RemoteRateLimiter {
+    async fn acquire(&self, route: &Route<'_>) {
+        if let Err(e) = self.call(&Request::Acquire { route: RouteKey::new(route) }).await {
+            warn!("Rate limit service unavailable ({}), using local limiter", e);
+            self.fallback.await_bucket(route).await;
+        }
+    }
+
+    async fn update(&self, route: &Route<'_>, headers: &HeaderMap) {
+        let forwarded = headers
+            .iter()
+            .filter(|(name, _)| name.as_str().starts_with("x-ratelimit-"))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        if let Err(e) = self.call(&Request::Update { route: RouteKey::new(route), headers: forwarded }).await {
+            warn!("Rate limit service unavailable ({}), using local limiter", e);
+        }
+        // Keep the fallback warm so losing the service doesn't start from nothing.
+        self.fallback.update(route, headers).await;
+    }
+
+    async fn bucket_limited(&self, route: &Route<'_>, retry_after: f64) {
+        let request = Request::BucketLimited { route: RouteKey::new(route), retry_after };
+        if let Err(e) = self.call(&request).await {
+            warn!("Rate limit service unavailable ({}), using local limiter", e);
+        }
+        self.fallback.handle_bucket_limit(route, retry_after).await;
+    }
+
+    async fn global_limited(&self, retry_after: f64) {
+        if let Err(e) = self.call(&Request::GlobalLimited { retry_after }).await {
+            warn!("Rate limit service unavailable ({}), using local limiter", e);
+            self.fallback.handle_global_limit(retry_after).await;
+        }
+    }
+}
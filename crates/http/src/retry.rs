@@ -0,0 +1,105 @@
+//! Retrying of requests that failed because of Discord or the network
+//! rather than because of the request itself.
+
+use rand::Rng;
+use reqwest::{Method, StatusCode};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every further attempt.
+    pub base_delay: Duration,
+    /// Upper bound for a single backoff.
+    pub max_delay: Duration,
+    /// Also retry POST/PATCH after the request may have reached Discord.
+    /// Off by default, since it can e.g. send a message twice.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    pub fn retry_non_idempotent(mut self, enabled: bool) -> Self {
+        self.retry_non_idempotent = enabled;
+        self
+    }
+
+    /// Backoff before retry number `retry` (starting at 1): the exponential
+    /// delay, jittered down by up to half.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(1u32 << retry.saturating_sub(1).min(16))
+            .min(self.max_delay);
+        let max_ms = exp.as_millis() as u64;
+        if max_ms == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis(rand::thread_rng().gen_range(max_ms / 2..=max_ms))
+    }
+
+    /// Whether a request that got `status` should be sent again.
+    pub fn should_retry_status(&self, method: &Method, status: StatusCode, attempt: u32) -> bool {
+        attempt < self.max_attempts
+            && is_retryable_status(status)
+            && (self.retry_non_idempotent || is_idempotent(method))
+    }
+
+    /// Whether a request that failed with a transport error should be sent
+    /// again. Connection errors are always safe, the request never left.
+    pub fn should_retry_error(&self, method: &Method, error: &reqwest::Error, attempt: u32) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        error.is_connect() || self.retry_non_idempotent || is_idempotent(method)
+    }
+}
+
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 500 | 502 | 503 | 504)
+}
+
+pub fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
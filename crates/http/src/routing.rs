@@ -1,8 +1,79 @@
+use discord_rs_core::traits::query_pairs;
+use discord_rs_core::{Result, Snowflake};
+use discord_rs_model::reaction::ReactionType;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use reqwest::Method;
+use serde::Serialize;
 use std::fmt::Display;
 use std::borrow::Cow;
 
+/// Discord's snowflake epoch (2015-01-01T00:00:00Z) in milliseconds.
+const DISCORD_EPOCH_MS: u64 = 1_420_070_400_000;
+
+/// Messages older than this are deleted through a separate, stricter bucket.
+const OLD_MESSAGE_MS: u64 = 14 * 24 * 60 * 60 * 1000;
+
+/// One segment of a route path, classified by how it affects rate limiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment<'p> {
+    /// A fixed path component such as `channels`, `messages` or `@me`.
+    Literal(&'p str),
+    /// A snowflake id.
+    Id,
+    /// A webhook or interaction token.
+    Token,
+    /// An emoji in `/reactions/{emoji}`, either percent-encoded unicode or `name:id`.
+    Emoji,
+}
+
+/// The top-level resource Discord scopes a bucket to. Requests with the same bucket hash
+/// but different major parameters are limited independently.
+///
+/// Webhook tokens are kept only as a hash, so bucket keys and logs never contain them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MajorParameter {
+    None,
+    Channel(u64),
+    Guild(u64),
+    Webhook { id: u64, token_hash: Option<u64> },
+    Interaction(u64),
+}
+
+impl MajorParameter {
+    pub fn webhook(id: u64, token: Option<&str>) -> Self {
+        MajorParameter::Webhook {
+            id,
+            token_hash: token.map(hash_token),
+        }
+    }
+}
+
+// FNV-1a: stable across runs and processes, unlike `DefaultHasher`, so keys
+// stay comparable when they're shared with a remote limiter.
+fn hash_token(token: &str) -> u64 {
+    token.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+impl Display for MajorParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MajorParameter::None => Ok(()),
+            MajorParameter::Channel(id) => write!(f, "channel:{}", id),
+            MajorParameter::Guild(id) => write!(f, "guild:{}", id),
+            MajorParameter::Webhook { id, token_hash: None } => write!(f, "webhook:{}", id),
+            MajorParameter::Webhook { id, token_hash: Some(hash) } => write!(f, "webhook:{}:{:016x}", id, hash),
+            MajorParameter::Interaction(id) => write!(f, "interaction:{}", id),
+        }
+    }
+}
+
 /// Represents an API route, used for rate limiting grouping.
+///
+/// The path is parsed into typed [`Segment`]s: ids, tokens and reaction emojis are
+/// normalised away so that e.g. every `PUT .../reactions/{emoji}/@me` on a channel lands in
+/// the same bucket, and webhook/interaction tokens never leak into bucket keys.
 #[derive(Debug, Clone)]
 pub struct Route<'a> {
     pub path: Cow<'a, str>,
@@ -17,37 +88,110 @@ impl<'a> Route<'a> {
         }
     }
 
-    pub fn bucket_key(&self) -> String {
-        
-        let parts: Vec<&str> = self.path.split('/').collect();
-        let mut key_parts = Vec::new();
-        
-        let mut i = 0;
-        while i < parts.len() {
-            let part = parts[i];
-            if part.is_empty() {
-                i += 1;
-                continue;
-            }
-
-            key_parts.push(part);
-
-            if matches!(part, "channels" | "guilds" | "webhooks") {
-                if let Some(next) = parts.get(i + 1) {
-                    key_parts.push(next);
-                    i += 1;
+    fn parts(&self) -> Vec<&str> {
+        // Query parameters never affect which bucket a request falls into.
+        let path = self.path.split('?').next().unwrap_or_default();
+        path.split('/').filter(|p| !p.is_empty()).collect()
+    }
+
+    pub fn segments(&self) -> Vec<Segment<'_>> {
+        let parts = self.parts();
+        let root = parts.first().copied().unwrap_or_default();
+
+        parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| {
+                let previous = i.checked_sub(1).map(|p| parts[p]);
+                if previous == Some("reactions") {
+                    Segment::Emoji
+                } else if i == 2 && matches!(root, "webhooks" | "interactions") {
+                    Segment::Token
+                } else if !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()) {
+                    Segment::Id
+                } else {
+                    Segment::Literal(part)
                 }
-            } 
-            
-            else if part.chars().all(char::is_numeric) {
-                 key_parts.pop();
-                 key_parts.push(":id");
+            })
+            .collect()
+    }
+
+    pub fn major_parameter(&self) -> MajorParameter {
+        let parts = self.parts();
+        let id = parts.get(1).and_then(|id| id.parse::<u64>().ok());
+        match (parts.first().copied(), id) {
+            (Some("channels"), Some(id)) => MajorParameter::Channel(id),
+            (Some("guilds"), Some(id)) => MajorParameter::Guild(id),
+            (Some("webhooks"), Some(id)) => MajorParameter::webhook(id, parts.get(2).copied()),
+            (Some("interactions"), Some(id)) => MajorParameter::Interaction(id),
+            _ => MajorParameter::None,
+        }
+    }
+
+    /// `DELETE /channels/{id}/messages/{id}` for a message older than two weeks.
+    pub fn is_old_message_delete(&self, now_ms: u64) -> bool {
+        if self.method != Method::DELETE {
+            return false;
+        }
+        let parts = self.parts();
+        if parts.len() != 4 || parts[0] != "channels" || parts[2] != "messages" {
+            return false;
+        }
+        match parts[3].parse::<u64>() {
+            Ok(message_id) => {
+                let created_ms = (message_id >> 22) + DISCORD_EPOCH_MS;
+                now_ms.saturating_sub(created_ms) > OLD_MESSAGE_MS
             }
-            
-            i += 1;
+            Err(_) => false,
         }
+    }
+
+    /// The route with every parameter normalised, e.g. `GET:channels/:id/messages/:id`.
+    /// Routes with the same template always share a bucket hash.
+    ///
+    /// This ignores the old-message delete bucket; use [`Route::template_at`] when the
+    /// current time is known.
+    pub fn template(&self) -> String {
+        let path = self
+            .segments()
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(part) => *part,
+                Segment::Id => ":id",
+                Segment::Token => ":token",
+                Segment::Emoji => ":emoji",
+            })
+            .collect::<Vec<_>>()
+            .join("/");
 
-        format!("{}:{}", self.method, key_parts.join("/"))
+        format!("{}:{}", self.method, path)
+    }
+
+    /// [`Route::template`] as of `now_ms` (Unix milliseconds), with deletes of messages
+    /// older than two weeks split into their own `:old` template.
+    pub fn template_at(&self, now_ms: u64) -> String {
+        if self.is_old_message_delete(now_ms) {
+            format!("{}:old", self.template())
+        } else {
+            self.template()
+        }
+    }
+
+    /// The key used to track this route until Discord reports its bucket hash.
+    pub fn bucket_key(&self) -> String {
+        self.with_major(self.template())
+    }
+
+    /// [`Route::bucket_key`] as of `now_ms`; see [`Route::template_at`].
+    pub fn bucket_key_at(&self, now_ms: u64) -> String {
+        self.with_major(self.template_at(now_ms))
+    }
+
+    fn with_major(&self, template: String) -> String {
+        match self.major_parameter() {
+            MajorParameter::None => template,
+            major => format!("{}@{}", template, major),
+        }
     }
 }
 
@@ -56,3 +200,231 @@ impl<'a> Display for Route<'a> {
         write!(f, "{}", self.path)
     }
 }
+
+// Unicode emojis must be percent-encoded; `name:id` is left readable.
+const EMOJI_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b':').remove(b'_').remove(b'-');
+
+/// Every REST resource the client talks to. [`Endpoint::path`] renders the path
+/// without a query string; paired with a method it forms a [`Route`].
+#[derive(Debug, Clone, Copy)]
+pub enum Endpoint<'a> {
+    GatewayBot,
+
+    CurrentUser,
+    User { user_id: Snowflake },
+    CurrentUserGuilds,
+    CurrentUserGuild { guild_id: Snowflake },
+    CurrentUserGuildMember { guild_id: Snowflake },
+
+    ChannelMessages { channel_id: Snowflake },
+    ChannelMessage { channel_id: Snowflake, message_id: Snowflake },
+    BulkDeleteMessages { channel_id: Snowflake },
+    CrosspostMessage { channel_id: Snowflake, message_id: Snowflake },
+    MessageReactions { channel_id: Snowflake, message_id: Snowflake },
+    MessageReaction { channel_id: Snowflake, message_id: Snowflake, emoji: &'a ReactionType },
+    OwnReaction { channel_id: Snowflake, message_id: Snowflake, emoji: &'a ReactionType },
+    UserReaction { channel_id: Snowflake, message_id: Snowflake, emoji: &'a ReactionType, user_id: Snowflake },
+    MessageThreads { channel_id: Snowflake, message_id: Snowflake },
+    ChannelThreads { channel_id: Snowflake },
+    PublicArchivedThreads { channel_id: Snowflake },
+    PrivateArchivedThreads { channel_id: Snowflake },
+    JoinedPrivateArchivedThreads { channel_id: Snowflake },
+    ThreadMembers { thread_id: Snowflake },
+    ThreadMember { thread_id: Snowflake, user_id: Snowflake },
+    CurrentThreadMember { thread_id: Snowflake },
+    ChannelWebhooks { channel_id: Snowflake },
+    ChannelFollowers { channel_id: Snowflake },
+
+    Guild { guild_id: Snowflake },
+    GuildPreview { guild_id: Snowflake },
+    GuildChannels { guild_id: Snowflake },
+    GuildPrune { guild_id: Snowflake },
+    GuildWidgetSettings { guild_id: Snowflake },
+    GuildWidget { guild_id: Snowflake },
+    GuildVanityUrl { guild_id: Snowflake },
+    GuildAuditLog { guild_id: Snowflake },
+    GuildActiveThreads { guild_id: Snowflake },
+    GuildWebhooks { guild_id: Snowflake },
+    GuildRoles { guild_id: Snowflake },
+    GuildRole { guild_id: Snowflake, role_id: Snowflake },
+    GuildMembers { guild_id: Snowflake },
+    GuildMember { guild_id: Snowflake, user_id: Snowflake },
+    GuildMemberRole { guild_id: Snowflake, user_id: Snowflake, role_id: Snowflake },
+    GuildBans { guild_id: Snowflake },
+    GuildBan { guild_id: Snowflake, user_id: Snowflake },
+    GuildBulkBan { guild_id: Snowflake },
+    GuildEmojis { guild_id: Snowflake },
+    GuildEmoji { guild_id: Snowflake, emoji_id: Snowflake },
+    GuildStickers { guild_id: Snowflake },
+    GuildSticker { guild_id: Snowflake, sticker_id: Snowflake },
+    AutoModerationRules { guild_id: Snowflake },
+    AutoModerationRule { guild_id: Snowflake, rule_id: Snowflake },
+
+    GlobalCommands { application_id: Snowflake },
+    GlobalCommand { application_id: Snowflake, command_id: Snowflake },
+    GuildCommands { application_id: Snowflake, guild_id: Snowflake },
+    GuildCommand { application_id: Snowflake, guild_id: Snowflake, command_id: Snowflake },
+    GuildCommandPermissions { application_id: Snowflake, guild_id: Snowflake },
+    CommandPermissions { application_id: Snowflake, guild_id: Snowflake, command_id: Snowflake },
+    ApplicationEmojis { application_id: Snowflake },
+    ApplicationEmoji { application_id: Snowflake, emoji_id: Snowflake },
+
+    Sticker { sticker_id: Snowflake },
+    StickerPacks,
+
+    Webhook { webhook_id: Snowflake },
+    WebhookWithToken { webhook_id: Snowflake, token: &'a str },
+    WebhookMessage { webhook_id: Snowflake, token: &'a str, message_id: Snowflake },
+    OriginalInteractionResponse { application_id: Snowflake, token: &'a str },
+    InteractionCallback { interaction_id: Snowflake, token: &'a str },
+}
+
+impl<'a> Endpoint<'a> {
+    pub fn path(&self) -> String {
+        use Endpoint::*;
+        match *self {
+            GatewayBot => "/gateway/bot".to_string(),
+
+            CurrentUser => "/users/@me".to_string(),
+            User { user_id } => format!("/users/{}", user_id),
+            CurrentUserGuilds => "/users/@me/guilds".to_string(),
+            CurrentUserGuild { guild_id } => format!("/users/@me/guilds/{}", guild_id),
+            CurrentUserGuildMember { guild_id } => format!("/users/@me/guilds/{}/member", guild_id),
+
+            ChannelMessages { channel_id } => format!("/channels/{}/messages", channel_id),
+            ChannelMessage { channel_id, message_id } => {
+                format!("/channels/{}/messages/{}", channel_id, message_id)
+            }
+            BulkDeleteMessages { channel_id } => format!("/channels/{}/messages/bulk-delete", channel_id),
+            CrosspostMessage { channel_id, message_id } => {
+                format!("/channels/{}/messages/{}/crosspost", channel_id, message_id)
+            }
+            MessageReactions { channel_id, message_id } => {
+                format!("/channels/{}/messages/{}/reactions", channel_id, message_id)
+            }
+            MessageReaction { channel_id, message_id, emoji } => format!(
+                "/channels/{}/messages/{}/reactions/{}",
+                channel_id,
+                message_id,
+                encode_emoji(emoji)
+            ),
+            OwnReaction { channel_id, message_id, emoji } => format!(
+                "/channels/{}/messages/{}/reactions/{}/@me",
+                channel_id,
+                message_id,
+                encode_emoji(emoji)
+            ),
+            UserReaction { channel_id, message_id, emoji, user_id } => format!(
+                "/channels/{}/messages/{}/reactions/{}/{}",
+                channel_id,
+                message_id,
+                encode_emoji(emoji),
+                user_id
+            ),
+            MessageThreads { channel_id, message_id } => {
+                format!("/channels/{}/messages/{}/threads", channel_id, message_id)
+            }
+            ChannelThreads { channel_id } => format!("/channels/{}/threads", channel_id),
+            PublicArchivedThreads { channel_id } => format!("/channels/{}/threads/archived/public", channel_id),
+            PrivateArchivedThreads { channel_id } => format!("/channels/{}/threads/archived/private", channel_id),
+            JoinedPrivateArchivedThreads { channel_id } => {
+                format!("/channels/{}/users/@me/threads/archived/private", channel_id)
+            }
+            ThreadMembers { thread_id } => format!("/channels/{}/thread-members", thread_id),
+            ThreadMember { thread_id, user_id } => format!("/channels/{}/thread-members/{}", thread_id, user_id),
+            CurrentThreadMember { thread_id } => format!("/channels/{}/thread-members/@me", thread_id),
+            ChannelWebhooks { channel_id } => format!("/channels/{}/webhooks", channel_id),
+            ChannelFollowers { channel_id } => format!("/channels/{}/followers", channel_id),
+
+            Guild { guild_id } => format!("/guilds/{}", guild_id),
+            GuildPreview { guild_id } => format!("/guilds/{}/preview", guild_id),
+            GuildChannels { guild_id } => format!("/guilds/{}/channels", guild_id),
+            GuildPrune { guild_id } => format!("/guilds/{}/prune", guild_id),
+            GuildWidgetSettings { guild_id } => format!("/guilds/{}/widget", guild_id),
+            GuildWidget { guild_id } => format!("/guilds/{}/widget.json", guild_id),
+            GuildVanityUrl { guild_id } => format!("/guilds/{}/vanity-url", guild_id),
+            GuildAuditLog { guild_id } => format!("/guilds/{}/audit-logs", guild_id),
+            GuildActiveThreads { guild_id } => format!("/guilds/{}/threads/active", guild_id),
+            GuildWebhooks { guild_id } => format!("/guilds/{}/webhooks", guild_id),
+            GuildRoles { guild_id } => format!("/guilds/{}/roles", guild_id),
+            GuildRole { guild_id, role_id } => format!("/guilds/{}/roles/{}", guild_id, role_id),
+            GuildMembers { guild_id } => format!("/guilds/{}/members", guild_id),
+            GuildMember { guild_id, user_id } => format!("/guilds/{}/members/{}", guild_id, user_id),
+            GuildMemberRole { guild_id, user_id, role_id } => {
+                format!("/guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id)
+            }
+            GuildBans { guild_id } => format!("/guilds/{}/bans", guild_id),
+            GuildBan { guild_id, user_id } => format!("/guilds/{}/bans/{}", guild_id, user_id),
+            GuildBulkBan { guild_id } => format!("/guilds/{}/bulk-ban", guild_id),
+            GuildEmojis { guild_id } => format!("/guilds/{}/emojis", guild_id),
+            GuildEmoji { guild_id, emoji_id } => format!("/guilds/{}/emojis/{}", guild_id, emoji_id),
+            GuildStickers { guild_id } => format!("/guilds/{}/stickers", guild_id),
+            GuildSticker { guild_id, sticker_id } => format!("/guilds/{}/stickers/{}", guild_id, sticker_id),
+            AutoModerationRules { guild_id } => format!("/guilds/{}/auto-moderation/rules", guild_id),
+            AutoModerationRule { guild_id, rule_id } => {
+                format!("/guilds/{}/auto-moderation/rules/{}", guild_id, rule_id)
+            }
+
+            GlobalCommands { application_id } => format!("/applications/{}/commands", application_id),
+            GlobalCommand { application_id, command_id } => {
+                format!("/applications/{}/commands/{}", application_id, command_id)
+            }
+            GuildCommands { application_id, guild_id } => {
+                format!("/applications/{}/guilds/{}/commands", application_id, guild_id)
+            }
+            GuildCommand { application_id, guild_id, command_id } => {
+                format!("/applications/{}/guilds/{}/commands/{}", application_id, guild_id, command_id)
+            }
+            GuildCommandPermissions { application_id, guild_id } => {
+                format!("/applications/{}/guilds/{}/commands/permissions", application_id, guild_id)
+            }
+            CommandPermissions { application_id, guild_id, command_id } => format!(
+                "/applications/{}/guilds/{}/commands/{}/permissions",
+                application_id, guild_id, command_id
+            ),
+            ApplicationEmojis { application_id } => format!("/applications/{}/emojis", application_id),
+            ApplicationEmoji { application_id, emoji_id } => {
+                format!("/applications/{}/emojis/{}", application_id, emoji_id)
+            }
+
+            Sticker { sticker_id } => format!("/stickers/{}", sticker_id),
+            StickerPacks => "/sticker-packs".to_string(),
+
+            Webhook { webhook_id } => format!("/webhooks/{}", webhook_id),
+            WebhookWithToken { webhook_id, token } => format!("/webhooks/{}/{}", webhook_id, token),
+            WebhookMessage { webhook_id, token, message_id } => {
+                format!("/webhooks/{}/{}/messages/{}", webhook_id, token, message_id)
+            }
+            OriginalInteractionResponse { application_id, token } => {
+                format!("/webhooks/{}/{}/messages/@original", application_id, token)
+            }
+            InteractionCallback { interaction_id, token } => {
+                format!("/interactions/{}/{}/callback", interaction_id, token)
+            }
+        }
+    }
+
+    pub fn route(&self, method: Method) -> Route<'static> {
+        Route::new(method, self.path())
+    }
+}
+
+fn encode_emoji(emoji: &ReactionType) -> String {
+    utf8_percent_encode(&emoji.as_route_component(), EMOJI_ENCODE_SET).to_string()
+}
+
+/// Appends `query` to `path` as a URL-encoded query string, skipping `None` fields.
+pub fn with_query(path: &str, query: &impl Serialize) -> Result<String> {
+    Ok(append_query(path, &query_pairs(query)?))
+}
+
+/// Appends already split query pairs, e.g. `HttpRequest::query`, URL-encoded.
+pub(crate) fn append_query(path: &str, pairs: &[(String, String)]) -> String {
+    if pairs.is_empty() {
+        return path.to_string();
+    }
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish();
+    format!("{}?{}", path, query)
+}
@@ -0,0 +1,146 @@
+//! Client for a single webhook, authenticated by the token in its URL.
+
+use crate::client::{payload_form, RestClient, RestClientBuilder};
+use crate::routing::{with_query, Endpoint};
+use discord_rs_core::traits::FileUpload;
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use discord_rs_model::webhook::{EditWebhookMessage, ExecuteWebhook, ExecuteWebhookQuery, Webhook};
+use discord_rs_model::Message;
+use reqwest::Method;
+use serde::Serialize;
+
+#[derive(Debug, Clone)]
+pub struct WebhookClient {
+    rest: RestClient,
+    id: Snowflake,
+    token: String,
+}
+
+#[derive(Serialize)]
+struct ThreadQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread_id: Option<Snowflake>,
+}
+
+impl WebhookClient {
+    /// Standalone client; no bot token needed.
+    pub fn new(id: Snowflake, token: impl Into<String>) -> Result<Self> {
+        Ok(Self {
+            rest: RestClientBuilder::unauthenticated().build()?,
+            id,
+            token: token.into(),
+        })
+    }
+
+    /// Parses `https://discord.com/api/webhooks/{id}/{token}` (versioned
+    /// paths and the canary/ptb/discordapp.com hosts work too).
+    pub fn from_url(url: &str) -> Result<Self> {
+        let (id, token) = parse_webhook_url(url)?;
+        Self::new(id, token)
+    }
+
+    /// Client that goes through `rest`'s transport and rate limiter, without
+    /// its `Authorization` header.
+    pub fn with_rest(rest: &RestClient, id: Snowflake, token: impl Into<String>) -> Self {
+        Self {
+            rest: rest.without_authorization(),
+            id,
+            token: token.into(),
+        }
+    }
+
+    /// Client for a webhook fetched or created through `rest`. Fails for
+    /// webhooks without a token, i.e. anything but incoming webhooks.
+    pub fn from_webhook(rest: &RestClient, webhook: &Webhook) -> Result<Self> {
+        let token = webhook.token.as_deref().ok_or_else(|| {
+            DiscordError::Validation(format!("webhook {} has no token", webhook.id))
+        })?;
+        Ok(Self::with_rest(rest, webhook.id, token))
+    }
+
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the created message when `query.wait` is set, `None` otherwise.
+    pub async fn execute(&self, message: &ExecuteWebhook, query: &ExecuteWebhookQuery) -> Result<Option<Message>> {
+        let path = with_query(
+            &Endpoint::WebhookWithToken { webhook_id: self.id, token: &self.token }.path(),
+            query,
+        )?;
+        let value = self.send(Method::POST, &path, message, &message.files).await?;
+        if value.is_null() {
+            return Ok(None);
+        }
+        serde_json::from_value(value)
+            .map(Some)
+            .map_err(|e| DiscordError::Serialization(e.to_string()))
+    }
+
+    pub async fn get_message(&self, message_id: Snowflake, thread_id: Option<Snowflake>) -> Result<Message> {
+        let path = self.message_path(message_id, thread_id)?;
+        self.rest.request_typed(Method::GET, &path, None, None).await
+    }
+
+    pub async fn edit_message(
+        &self,
+        message_id: Snowflake,
+        edit: &EditWebhookMessage,
+        thread_id: Option<Snowflake>,
+    ) -> Result<Message> {
+        let path = self.message_path(message_id, thread_id)?;
+        let value = self.send(Method::PATCH, &path, edit, &edit.files).await?;
+        serde_json::from_value(value).map_err(|e| DiscordError::Serialization(e.to_string()))
+    }
+
+    pub async fn delete_message(&self, message_id: Snowflake, thread_id: Option<Snowflake>) -> Result<()> {
+        let path = self.message_path(message_id, thread_id)?;
+        self.rest.request(Method::DELETE, &path, None, None).await?;
+        Ok(())
+    }
+
+    fn message_path(&self, message_id: Snowflake, thread_id: Option<Snowflake>) -> Result<String> {
+        with_query(
+            &Endpoint::WebhookMessage { webhook_id: self.id, token: &self.token, message_id }.path(),
+            &ThreadQuery { thread_id },
+        )
+    }
+
+    /// JSON, or `payload_json` multipart when there are files.
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: &impl Serialize,
+        files: &[FileUpload],
+    ) -> Result<serde_json::Value> {
+        let body = serde_json::to_value(body).map_err(|e| DiscordError::Serialization(e.to_string()))?;
+        if files.is_empty() {
+            self.rest.request(method, path, Some(body), None).await
+        } else {
+            self.rest
+                .request_multipart_retryable(method, path, || payload_form(&body, files), None)
+                .await
+        }
+    }
+}
+
+fn parse_webhook_url(url: &str) -> Result<(Snowflake, String)> {
+    let invalid = || DiscordError::Validation(format!("invalid webhook url: {}", url));
+
+    let parsed = url::Url::parse(url).map_err(|_| invalid())?;
+    let segments: Vec<&str> = parsed.path_segments().ok_or_else(invalid)?.collect();
+    let start = segments.iter().position(|s| *s == "webhooks").ok_or_else(invalid)?;
+
+    match &segments[start + 1..] {
+        [id, token, ..] if !token.is_empty() => {
+            let id = id.parse::<u64>().map_err(|_| invalid())?;
+            Ok((Snowflake::new(id), token.to_string()))
+        }
+        _ => Err(invalid()),
+    }
+}
@@ -0,0 +1,79 @@
+use discord_rs_core::Snowflake;
+use discord_rs_http::pagination::Pagination;
+use discord_rs_model::audit_log::{AuditLogEvent, GetGuildAuditLog};
+use futures::StreamExt;
+
+mod common;
+
+use common::{client, json_response, request_line};
+
+fn audit_log(ids: impl IntoIterator<Item = u64>) -> String {
+    let entries: Vec<_> = ids
+        .into_iter()
+        .map(|id| serde_json::json!({ "id": id.to_string(), "user_id": "3", "target_id": "4", "action_type": 22 }))
+        .collect();
+    let log = serde_json::json!({
+        "audit_log_entries": entries,
+        "users": [{ "id": "3", "username": "mod", "discriminator": "0" }],
+        "webhooks": [],
+        "threads": [],
+        "integrations": [],
+        "application_commands": []
+    });
+    json_response(&log.to_string())
+}
+
+#[tokio::test]
+async fn test_get_guild_audit_log_sends_filters() {
+    let (client, mut requests) = client(vec![audit_log([5])]).await;
+
+    let log = client
+        .get_guild_audit_log(
+            Snowflake::new(1),
+            &GetGuildAuditLog::new()
+                .user_id(Snowflake::new(3))
+                .action_type(AuditLogEvent::MemberBanAdd)
+                .limit(10),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(log.audit_log_entries.len(), 1);
+    assert_eq!(log.users[0].username, "mod");
+    let request = requests.recv().await.unwrap();
+    assert_eq!(
+        request_line(&request),
+        "GET /api/v10/guilds/1/audit-logs?action_type=22&limit=10&user_id=3 HTTP/1.1"
+    );
+}
+
+#[tokio::test]
+async fn test_audit_log_stream_pages_backward_and_keeps_filters() {
+    let first: Vec<u64> = (101..=200).rev().collect();
+    let (client, mut requests) = client(vec![audit_log(first), audit_log([100, 99])]).await;
+
+    let filter = GetGuildAuditLog::new()
+        .action_type(AuditLogEvent::MemberBanAdd)
+        .before(Snowflake::new(1))
+        .limit(1);
+    let ids: Vec<u64> = client
+        .stream_guild_audit_log(Snowflake::new(1), filter, Pagination::new())
+        .map(|entry| u64::from(entry.unwrap().id))
+        .collect()
+        .await;
+
+    assert_eq!(ids.len(), 102);
+    assert_eq!(ids[0], 200);
+    assert_eq!(ids[101], 99);
+
+    let first = requests.recv().await.unwrap();
+    assert_eq!(
+        request_line(&first),
+        "GET /api/v10/guilds/1/audit-logs?action_type=22&limit=100 HTTP/1.1"
+    );
+    let second = requests.recv().await.unwrap();
+    assert_eq!(
+        request_line(&second),
+        "GET /api/v10/guilds/1/audit-logs?action_type=22&before=101&limit=100 HTTP/1.1"
+    );
+}
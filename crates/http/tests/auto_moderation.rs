@@ -0,0 +1,103 @@
+use discord_rs_core::Snowflake;
+use discord_rs_model::auto_moderation::{
+    AutoModerationAction, AutoModerationEventType, AutoModerationTriggerMetadata, AutoModerationTriggerType,
+    CreateAutoModerationRule, ModifyAutoModerationRule,
+};
+
+mod common;
+
+use common::{client, json_response, request_line, NO_CONTENT};
+
+const RULE: &str = r#"{
+    "id": "9",
+    "guild_id": "1",
+    "name": "Mentions",
+    "creator_id": "3",
+    "event_type": 1,
+    "trigger_type": 5,
+    "trigger_metadata": { "mention_total_limit": 10, "mention_raid_protection_enabled": true },
+    "actions": [{ "type": 1, "metadata": {} }],
+    "enabled": true,
+    "exempt_roles": [],
+    "exempt_channels": []
+}"#;
+
+#[tokio::test]
+async fn test_create_and_modify_rules() {
+    let (client, mut requests) = client(vec![json_response(RULE), json_response(RULE)]).await;
+
+    let rule = client
+        .create_auto_moderation_rule(
+            Snowflake::new(1),
+            &CreateAutoModerationRule::new(
+                "Mentions",
+                AutoModerationEventType::MessageSend,
+                AutoModerationTriggerType::MentionSpam,
+            )
+            .trigger_metadata(AutoModerationTriggerMetadata::new().mention_total_limit(10))
+            .action(AutoModerationAction::block_message(None)),
+            Some("raids"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(rule.trigger_metadata.mention_total_limit, Some(10));
+
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "POST /api/v10/guilds/1/auto-moderation/rules HTTP/1.1");
+    assert!(request.to_lowercase().contains("x-audit-log-reason: raids"));
+    assert!(request.ends_with(
+        r#"{"actions":[{"type":1}],"event_type":1,"name":"Mentions","trigger_metadata":{"mention_total_limit":10},"trigger_type":5}"#
+    ));
+
+    client
+        .modify_auto_moderation_rule(
+            Snowflake::new(1),
+            rule.id,
+            &ModifyAutoModerationRule::new().enabled(false),
+            None,
+        )
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "PATCH /api/v10/guilds/1/auto-moderation/rules/9 HTTP/1.1");
+    assert!(request.ends_with(r#"{"enabled":false}"#));
+}
+
+#[tokio::test]
+async fn test_list_get_and_delete_rules() {
+    let (client, mut requests) = client(vec![
+        json_response(&format!("[{}]", RULE)),
+        json_response(RULE),
+        NO_CONTENT.to_string(),
+    ])
+    .await;
+
+    let rules = client.list_auto_moderation_rules(Snowflake::new(1)).await.unwrap();
+    assert_eq!(rules.len(), 1);
+    let rule = client
+        .get_auto_moderation_rule(Snowflake::new(1), Snowflake::new(9))
+        .await
+        .unwrap();
+    assert_eq!(rule.name, "Mentions");
+    client
+        .delete_auto_moderation_rule(Snowflake::new(1), Snowflake::new(9), None)
+        .await
+        .unwrap();
+
+    let lines: Vec<String> = [
+        requests.recv().await.unwrap(),
+        requests.recv().await.unwrap(),
+        requests.recv().await.unwrap(),
+    ]
+    .iter()
+    .map(|r| request_line(r).to_string())
+    .collect();
+    assert_eq!(
+        lines,
+        vec![
+            "GET /api/v10/guilds/1/auto-moderation/rules HTTP/1.1",
+            "GET /api/v10/guilds/1/auto-moderation/rules/9 HTTP/1.1",
+            "DELETE /api/v10/guilds/1/auto-moderation/rules/9 HTTP/1.1",
+        ]
+    );
+}
@@ -0,0 +1,32 @@
+use discord_rs_core::{Config, DiscordError};
+use discord_rs_http::RestClient;
+use std::sync::Arc;
+
+mod common;
+
+use common::{response, serve};
+
+#[tokio::test]
+async fn test_download_returns_the_body_without_authorization() {
+    let png = "HTTP/1.1 200 OK\r\ncontent-type: image/png\r\ncontent-length: 4\r\nconnection: close\r\n\r\n\u{1}PNG";
+    let (base, mut requests) = serve(vec![png]).await;
+    let client = RestClient::new(Arc::new(Config::new("token"))).unwrap();
+
+    let bytes = client.download(&format!("{}/avatars/1/abc.png", base)).await.unwrap();
+
+    assert_eq!(bytes, b"\x01PNG");
+    let head = requests.recv().await.unwrap().to_lowercase();
+    assert!(head.starts_with("get /api/avatars/1/abc.png "));
+    assert!(!head.contains("authorization:"));
+}
+
+#[tokio::test]
+async fn test_download_maps_error_statuses() {
+    let (base, _requests) = serve(vec![response("404 Not Found", "Not Found")]).await;
+    let client = RestClient::new(Arc::new(Config::new("token"))).unwrap();
+
+    let err = client.download(&format!("{}/icons/1/missing.png", base)).await.unwrap_err();
+
+    assert_eq!(err.api_code(), None);
+    assert!(matches!(err, DiscordError::Http(ref m) if m.contains("404")));
+}
@@ -0,0 +1,210 @@
+use discord_rs_core::{Config, DiscordError};
+use discord_rs_http::invalid_requests::InvalidRequestConfig;
+use discord_rs_http::retry::RetryPolicy;
+use discord_rs_http::{RestClient, RestClientBuilder};
+use reqwest::Method;
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+
+use common::serve;
+
+const OK: &str = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{}";
+const BUCKET_429: &str = "HTTP/1.1 429 Too Many Requests\r\ncontent-type: application/json\r\nx-ratelimit-scope: user\r\ncontent-length: 36\r\nconnection: close\r\n\r\n{\"retry_after\":0.0,\"global\":false}  ";
+
+const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+fn config() -> Arc<Config> {
+    Arc::new(Config::new("token"))
+}
+
+#[tokio::test]
+async fn test_builder_sets_base_url_version_and_user_agent() {
+    let (base, mut requests) = serve(vec![OK]).await;
+    let client = RestClient::builder(config())
+        .base_url(base)
+        .api_version(9)
+        .user_agent_suffix("my-bot/1.2")
+        .build()
+        .unwrap();
+
+    client.request(Method::GET, "/users/@me", None, None).await.unwrap();
+
+    let head = requests.recv().await.unwrap().to_lowercase();
+    assert!(head.starts_with("get /api/v9/users/@me "));
+    assert!(head.contains("user-agent: discordbot (discord.rs, 0.1.0) my-bot/1.2"));
+    assert!(head.contains("authorization: bot token"));
+}
+
+#[tokio::test]
+async fn test_injected_client_still_sends_authorization() {
+    let (base, mut requests) = serve(vec![OK]).await;
+    let client = RestClient::builder(config())
+        .base_url(base)
+        .http_client(reqwest::Client::new())
+        .proxy_mode(true)
+        .build()
+        .unwrap();
+
+    assert!(client.is_proxy_mode());
+    client.request(Method::GET, "/gateway", None, None).await.unwrap();
+
+    let head = requests.recv().await.unwrap().to_lowercase();
+    assert!(head.contains("authorization: bot token"));
+}
+
+#[tokio::test]
+async fn test_unauthenticated_client_sends_no_authorization() {
+    let (base, mut requests) = serve(vec![OK]).await;
+    let client = RestClientBuilder::unauthenticated().base_url(base).build().unwrap();
+
+    client.request(Method::GET, "/webhooks/1/tok", None, None).await.unwrap();
+
+    let head = requests.recv().await.unwrap().to_lowercase();
+    assert!(head.starts_with("get /api/v10/webhooks/1/tok "));
+    assert!(!head.contains("authorization:"));
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_rate_limit_retries() {
+    let (base, _requests) = serve(vec![BUCKET_429, BUCKET_429, BUCKET_429]).await;
+    let client = RestClient::builder(config())
+        .base_url(base)
+        .timeout(Some(Duration::from_secs(5)))
+        .invalid_request_config(InvalidRequestConfig {
+            max_rate_limit_retries: 2,
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    let err = client.request(Method::GET, "/channels/1", None, None).await.unwrap_err();
+    assert!(matches!(err, DiscordError::RateLimit));
+    assert_eq!(client.invalid_requests().count(), 3);
+}
+
+#[tokio::test]
+async fn test_gives_up_without_waiting_out_the_last_rate_limit() {
+    const GLOBAL_429: &str = "HTTP/1.1 429 Too Many Requests\r\ncontent-type: application/json\r\nx-ratelimit-global: true\r\ncontent-length: 34\r\nconnection: close\r\n\r\n{\"retry_after\":30.0,\"global\":true}";
+    const SLOW_429: &str = "HTTP/1.1 429 Too Many Requests\r\ncontent-type: application/json\r\nx-ratelimit-scope: user\r\ncontent-length: 35\r\nconnection: close\r\n\r\n{\"retry_after\":30.0,\"global\":false}";
+
+    for (response, proxy_mode) in [(GLOBAL_429, false), (SLOW_429, true)] {
+        let (base, _requests) = serve(vec![response]).await;
+        let client = RestClient::builder(config())
+            .base_url(base)
+            .proxy_mode(proxy_mode)
+            .invalid_request_config(InvalidRequestConfig {
+                max_rate_limit_retries: 0,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.request(Method::GET, "/channels/1", None, None),
+        )
+        .await
+        .expect("gave up only after sleeping through retry_after");
+        assert!(matches!(result, Err(DiscordError::RateLimit)));
+    }
+}
+
+fn fast_retries() -> RetryPolicy {
+    RetryPolicy::new().base_delay(Duration::from_millis(1))
+}
+
+#[tokio::test]
+async fn test_retries_idempotent_request_on_5xx() {
+    let (base, mut requests) = serve(vec![UNAVAILABLE, OK]).await;
+    let client = RestClient::builder(config())
+        .base_url(base)
+        .retry_policy(fast_retries())
+        .build()
+        .unwrap();
+
+    client.request(Method::GET, "/channels/1", None, None).await.unwrap();
+    assert!(requests.recv().await.is_some());
+    assert!(requests.recv().await.is_some());
+}
+
+#[tokio::test]
+async fn test_does_not_retry_post_on_5xx() {
+    let (base, _requests) = serve(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+    let client = RestClient::builder(config())
+        .base_url(base)
+        .retry_policy(fast_retries())
+        .build()
+        .unwrap();
+
+    let err = client
+        .request(Method::POST, "/channels/1/messages", Some(serde_json::json!({})), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DiscordError::Http(ref m) if m.contains("503")));
+
+    // A per-request policy can opt in.
+    client
+        .request_with_retry(
+            Method::POST,
+            "/channels/1/messages",
+            Some(serde_json::json!({})),
+            None,
+            &fast_retries().retry_non_idempotent(true),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_multipart_upload_sends_payload_json_and_files() {
+    use discord_rs_core::traits::{FileUpload, Http};
+
+    let (base, mut requests) = serve(vec![OK]).await;
+    let client = RestClient::builder(config()).base_url(base).build().unwrap();
+
+    client
+        .post_multipart(
+            "/channels/1/messages",
+            serde_json::json!({ "content": "file" }),
+            vec![FileUpload::new("hello.txt", b"hello world".to_vec())],
+        )
+        .await
+        .unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.to_lowercase().contains("content-type: multipart/form-data"));
+    assert!(request.contains("name=\"payload_json\""));
+    assert!(request.contains("{\"content\":\"file\"}"));
+    assert!(request.contains("name=\"files[0]\"; filename=\"hello.txt\""));
+    assert!(request.contains("hello world"));
+}
+
+#[tokio::test]
+async fn test_http_trait_sends_query_reason_and_typed_response() {
+    use discord_rs_core::traits::{Http, HttpExt, HttpRequest};
+    use discord_rs_model::message::GetMessages;
+
+    let (base, mut requests) = serve(vec![
+        common::json_response("[]"),
+        common::json_response("{}"),
+    ])
+    .await;
+    let client = RestClient::builder(config()).base_url(base).build().unwrap();
+    let http: Arc<dyn Http> = Arc::new(client);
+
+    let request = HttpRequest::get("/channels/1/messages")
+        .query(&GetMessages::new().limit(5))
+        .unwrap();
+    let messages: Vec<serde_json::Value> = http.send_as(request).await.unwrap();
+    assert!(messages.is_empty());
+
+    let request = HttpRequest::put("/guilds/1/roles/3").reason("promote").query_param("x", 1);
+    http.send_empty(request).await.unwrap();
+
+    assert!(requests.recv().await.unwrap().starts_with("GET /api/v10/channels/1/messages?limit=5 "));
+    let put = requests.recv().await.unwrap();
+    assert!(put.starts_with("PUT /api/v10/guilds/1/roles/3?x=1 "));
+    assert!(put.to_lowercase().contains("x-audit-log-reason: promote"));
+}
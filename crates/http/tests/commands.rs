@@ -0,0 +1,48 @@
+use discord_rs_core::{Config, Snowflake};
+use discord_rs_http::RestClient;
+use discord_rs_model::interaction::CreateApplicationCommand;
+use std::sync::Arc;
+
+mod common;
+
+use common::{json_response, serve};
+
+const REGISTERED: &str = r#"[{"id":"1","application_id":"9","version":"1","type":1,"name":"ping","description":"Ping","dm_permission":true,"nsfw":false}]"#;
+
+fn client(base: String) -> RestClient {
+    RestClient::builder(Arc::new(Config::new("token"))).base_url(base).build().unwrap()
+}
+
+#[tokio::test]
+async fn test_sync_skips_overwrite_when_up_to_date() {
+    let (base, mut requests) = serve(vec![json_response(REGISTERED)]).await;
+    let commands = [CreateApplicationCommand::chat_input("ping", "Ping")];
+
+    let overwritten = client(base)
+        .sync_global_application_commands(Snowflake(9), &commands)
+        .await
+        .unwrap();
+
+    assert!(!overwritten);
+    let head = requests.recv().await.unwrap().to_lowercase();
+    assert!(head.starts_with("get /api/v10/applications/9/commands?with_localizations=true "));
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_sync_overwrites_changed_guild_commands() {
+    let (base, mut requests) = serve(vec![json_response(REGISTERED), json_response(REGISTERED)]).await;
+    let commands = [CreateApplicationCommand::chat_input("ping", "Check latency")];
+
+    let overwritten = client(base)
+        .sync_guild_application_commands(Snowflake(9), Snowflake(5), &commands)
+        .await
+        .unwrap();
+
+    assert!(overwritten);
+    let get = requests.recv().await.unwrap().to_lowercase();
+    assert!(get.starts_with("get /api/v10/applications/9/guilds/5/commands?with_localizations=true "));
+    let put = requests.recv().await.unwrap();
+    assert!(put.starts_with("PUT /api/v10/applications/9/guilds/5/commands "));
+    assert!(put.contains(r#""description":"Check latency""#));
+}
@@ -0,0 +1,90 @@
+#![allow(dead_code)]
+
+use discord_rs_core::Config;
+use discord_rs_http::RestClient;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+pub const NO_CONTENT: &str = "HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n";
+
+/// Serves `responses` in order, one per connection, and forwards the raw
+/// requests it receives.
+pub async fn serve(responses: Vec<impl Into<String> + Send + 'static>) -> (String, mpsc::UnboundedReceiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut socket).await;
+            let _ = tx.send(request);
+            socket.write_all(response.into().as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+        }
+    });
+
+    (format!("http://{}/api", addr), rx)
+}
+
+/// Reads one request, including a `content-length` or chunked body.
+async fn read_request(socket: &mut tokio::net::TcpStream) -> String {
+    let mut data = Vec::new();
+    let mut buf = [0; 8192];
+    loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        data.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&data).into_owned();
+        let Some(head_end) = text.find("\r\n\r\n") else {
+            if n == 0 { return text; }
+            continue;
+        };
+        let head = text[..head_end].to_lowercase();
+        let body_len = data.len() - head_end - 4;
+        let complete = if let Some(len) = head
+            .lines()
+            .find_map(|l| l.strip_prefix("content-length: "))
+        {
+            body_len >= len.trim().parse::<usize>().unwrap()
+        } else if head.contains("transfer-encoding: chunked") {
+            text.ends_with("0\r\n\r\n")
+        } else {
+            true
+        };
+        if complete || n == 0 {
+            return text;
+        }
+    }
+}
+
+/// Response with a JSON body, e.g. `response("404 Not Found", "{}")`.
+pub fn response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
+
+/// 200 response with a JSON body.
+pub fn json_response(body: &str) -> String {
+    response("200 OK", body)
+}
+
+/// `RestClient` against a `serve`d list of responses.
+pub async fn client(responses: Vec<String>) -> (RestClient, mpsc::UnboundedReceiver<String>) {
+    let (base, requests) = serve(responses).await;
+    let client = RestClient::builder(Arc::new(Config::new("token")))
+        .base_url(base)
+        .build()
+        .unwrap();
+    (client, requests)
+}
+
+/// First line of a raw request, e.g. `GET /api/v10/users/@me HTTP/1.1`.
+pub fn request_line(request: &str) -> &str {
+    request.lines().next().unwrap()
+}
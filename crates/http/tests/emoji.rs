@@ -0,0 +1,83 @@
+use discord_rs_core::traits::FileUpload;
+use discord_rs_core::{Config, Snowflake};
+use discord_rs_http::RestClient;
+use discord_rs_model::emoji::{CreateGuildEmoji, ModifyGuildEmoji};
+use discord_rs_model::image::ImageData;
+use discord_rs_model::sticker::{CreateGuildSticker, StickerFormatType};
+use std::sync::Arc;
+
+mod common;
+
+use common::{json_response, serve};
+
+const EMOJI: &str = r#"{"id":"41771983429993937","name":"LUL","animated":false}"#;
+
+const STICKER: &str = r#"{"id":"7","name":"wave","description":"","tags":"wave","type":2,"format_type":1,"guild_id":"2"}"#;
+
+fn client(base: String) -> RestClient {
+    RestClient::builder(Arc::new(Config::new("token"))).base_url(base).build().unwrap()
+}
+
+#[tokio::test]
+async fn test_create_guild_emoji_sends_data_uri() {
+    let (base, mut requests) = serve(vec![json_response(EMOJI)]).await;
+    let image = ImageData::with_content_type("image/png", b"hi!".to_vec());
+
+    let emoji = client(base)
+        .create_guild_emoji(Snowflake::new(2), &CreateGuildEmoji::new("LUL", &image), Some("requested"))
+        .await
+        .unwrap();
+
+    assert_eq!(emoji.name.as_deref(), Some("LUL"));
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/v10/guilds/2/emojis "));
+    assert!(request.ends_with(r#"{"image":"data:image/png;base64,aGkh","name":"LUL"}"#));
+}
+
+#[tokio::test]
+async fn test_modify_guild_emoji_can_clear_roles() {
+    let (base, mut requests) = serve(vec![json_response(EMOJI)]).await;
+
+    client(base)
+        .modify_guild_emoji(Snowflake::new(2), Snowflake::new(3), &ModifyGuildEmoji::new().roles(None), None)
+        .await
+        .unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("PATCH /api/v10/guilds/2/emojis/3 "));
+    assert!(request.ends_with(r#"{"roles":null}"#));
+}
+
+#[tokio::test]
+async fn test_list_application_emojis_unwraps_items() {
+    let (base, mut requests) = serve(vec![json_response(&format!(r#"{{"items":[{}]}}"#, EMOJI))]).await;
+
+    let emojis = client(base).list_application_emojis(Snowflake::new(9)).await.unwrap();
+
+    assert_eq!(emojis.len(), 1);
+    assert!(requests.recv().await.unwrap().starts_with("GET /api/v10/applications/9/emojis "));
+}
+
+#[tokio::test]
+async fn test_create_guild_sticker_uploads_form_fields() {
+    let (base, mut requests) = serve(vec![json_response(STICKER)]).await;
+    let sticker = CreateGuildSticker::new(
+        "wave",
+        "wave",
+        FileUpload::new("wave.png", b"png".to_vec()).content_type("image/png"),
+    )
+    .description("Hello!");
+
+    let created = client(base)
+        .create_guild_sticker(Snowflake::new(2), &sticker, None)
+        .await
+        .unwrap();
+
+    assert_eq!(created.format_type, StickerFormatType::Png);
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/v10/guilds/2/stickers "));
+    assert!(request.contains("name=\"name\"\r\n\r\nwave"));
+    assert!(request.contains("name=\"description\"\r\n\r\nHello!"));
+    assert!(request.contains("name=\"file\"; filename=\"wave.png\"\r\nContent-Type: image/png"));
+    assert!(!request.contains("payload_json"));
+}
@@ -0,0 +1,57 @@
+use discord_rs_core::{DiscordError, JsonErrorCode};
+use discord_rs_http::error::error_from_response;
+
+#[test]
+fn test_missing_permissions_is_matchable() {
+    let body = br#"{"message": "Missing Permissions", "code": 50013}"#;
+    let err = error_from_response(403, body);
+
+    assert_eq!(err.api_code(), Some(JsonErrorCode::MissingPermissions));
+    match err {
+        DiscordError::Api(api) => {
+            assert_eq!(api.status, 403);
+            assert_eq!(api.message, "Missing Permissions");
+            assert!(api.errors.is_empty());
+        }
+        other => panic!("expected Api error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_nested_field_errors_are_flattened() {
+    let body = br#"{
+        "code": 50035,
+        "message": "Invalid Form Body",
+        "errors": {
+            "content": {"_errors": [{"code": "BASE_TYPE_MAX_LENGTH", "message": "Must be 2000 or fewer in length."}]},
+            "embeds": {"0": {"title": {"_errors": [{"code": "BASE_TYPE_REQUIRED", "message": "This field is required"}]}}}
+        }
+    }"#;
+
+    let DiscordError::Api(api) = error_from_response(400, body) else {
+        panic!("expected Api error");
+    };
+    assert_eq!(api.code, JsonErrorCode::InvalidFormBody);
+
+    let paths: Vec<(&str, &str)> = api.errors.iter().map(|e| (e.path.as_str(), e.code.as_str())).collect();
+    assert!(paths.contains(&("content", "BASE_TYPE_MAX_LENGTH")));
+    assert!(paths.contains(&("embeds.0.title", "BASE_TYPE_REQUIRED")));
+}
+
+#[test]
+fn test_unknown_codes_round_trip() {
+    assert_eq!(JsonErrorCode::from(10008), JsonErrorCode::UnknownMessage);
+    assert_eq!(JsonErrorCode::from(12345), JsonErrorCode::Unknown(12345));
+    assert_eq!(u32::from(JsonErrorCode::Unknown(12345)), 12345);
+}
+
+#[test]
+fn test_non_json_body_keeps_status() {
+    let err = error_from_response(502, b"<html>Bad Gateway</html>");
+    assert!(err.api_code().is_none());
+    let DiscordError::Http(message) = err else {
+        panic!("expected Http error");
+    };
+    assert!(message.contains("502"));
+    assert!(message.contains("Bad Gateway"));
+}
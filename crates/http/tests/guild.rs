@@ -0,0 +1,174 @@
+use discord_rs_core::Snowflake;
+use discord_rs_model::channel::ChannelType;
+use discord_rs_model::guild::{
+    BeginGuildPrune, CreateGuildChannel, GetGuildPruneCount, GuildWidgetSettings, ModifyGuild,
+    ModifyGuildChannelPosition,
+};
+use discord_rs_model::image::ImageData;
+
+mod common;
+
+use common::{client, json_response, request_line, NO_CONTENT};
+
+const GUILD: &str = r#"{
+    "id": "1",
+    "name": "Test",
+    "icon": null,
+    "owner_id": "3",
+    "afk_timeout": 300,
+    "verification_level": 1,
+    "default_message_notifications": 0,
+    "explicit_content_filter": 0,
+    "mfa_level": 0,
+    "system_channel_flags": 0,
+    "premium_tier": 0,
+    "preferred_locale": "en-US",
+    "nsfw_level": 0,
+    "approximate_member_count": 42
+}"#;
+const CHANNEL: &str = r#"{"id":"2","type":0,"guild_id":"1","name":"general"}"#;
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+#[tokio::test]
+async fn test_get_and_modify_guild() {
+    let (client, mut requests) = client(vec![json_response(GUILD), json_response(GUILD)]).await;
+
+    let guild = client.get_guild(Snowflake::new(1), true).await.unwrap();
+    assert_eq!(guild.approximate_member_count, Some(42));
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "GET /api/v10/guilds/1?with_counts=true HTTP/1.1");
+
+    let modify = ModifyGuild {
+        name: Some("Renamed".to_string()),
+        icon: Some(Some(ImageData::new(PNG).unwrap())),
+        banner: Some(None),
+        ..Default::default()
+    };
+    client.modify_guild(Snowflake::new(1), &modify, Some("rebrand")).await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "PATCH /api/v10/guilds/1 HTTP/1.1");
+    assert!(request.to_lowercase().contains("x-audit-log-reason: rebrand"));
+    assert!(request.ends_with(r#"{"banner":null,"icon":"data:image/png;base64,iVBORw0KGgo=","name":"Renamed"}"#));
+}
+
+#[tokio::test]
+async fn test_guild_channel_requests() {
+    let (client, mut requests) = client(vec![
+        json_response(&format!("[{}]", CHANNEL)),
+        json_response(CHANNEL),
+        NO_CONTENT.to_string(),
+    ])
+    .await;
+
+    let channels = client.get_guild_channels(Snowflake::new(1)).await.unwrap();
+    assert_eq!(channels.len(), 1);
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "GET /api/v10/guilds/1/channels HTTP/1.1");
+
+    let mut channel = CreateGuildChannel::new("general", ChannelType::GuildText);
+    channel.parent_id = Some(Snowflake::new(4));
+    client.create_guild_channel(Snowflake::new(1), &channel, None).await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "POST /api/v10/guilds/1/channels HTTP/1.1");
+    assert!(request.ends_with(r#"{"name":"general","parent_id":"4","type":0}"#));
+
+    let positions = [
+        ModifyGuildChannelPosition {
+            id: Snowflake::new(2),
+            position: Some(0),
+            lock_permissions: None,
+            parent_id: Some(None),
+        },
+        ModifyGuildChannelPosition {
+            id: Snowflake::new(5),
+            position: None,
+            lock_permissions: Some(true),
+            parent_id: Some(Some(Snowflake::new(4))),
+        },
+    ];
+    client
+        .modify_guild_channel_positions(Snowflake::new(1), &positions, None)
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "PATCH /api/v10/guilds/1/channels HTTP/1.1");
+    assert!(request.ends_with(
+        r#"[{"id":"2","parent_id":null,"position":0},{"id":"5","lock_permissions":true,"parent_id":"4"}]"#
+    ));
+}
+
+#[tokio::test]
+async fn test_guild_prune_requests() {
+    let (client, mut requests) = client(vec![
+        json_response(r#"{"pruned":7}"#),
+        json_response(r#"{"pruned":null}"#),
+    ])
+    .await;
+
+    let query = GetGuildPruneCount {
+        days: Some(14),
+        include_roles: vec![Snowflake::new(5), Snowflake::new(6)],
+    };
+    let count = client.get_guild_prune_count(Snowflake::new(1), &query).await.unwrap();
+    assert_eq!(count.pruned, Some(7));
+    let request = requests.recv().await.unwrap();
+    assert_eq!(
+        request_line(&request),
+        "GET /api/v10/guilds/1/prune?days=14&include_roles=5%2C6 HTTP/1.1"
+    );
+
+    let prune = BeginGuildPrune {
+        days: Some(14),
+        compute_prune_count: Some(false),
+        include_roles: vec![Snowflake::new(5)],
+    };
+    let count = client.begin_guild_prune(Snowflake::new(1), &prune, Some("cleanup")).await.unwrap();
+    assert_eq!(count.pruned, None);
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "POST /api/v10/guilds/1/prune HTTP/1.1");
+    assert!(request.to_lowercase().contains("x-audit-log-reason: cleanup"));
+    assert!(request.ends_with(r#"{"compute_prune_count":false,"days":14,"include_roles":["5"]}"#));
+}
+
+#[tokio::test]
+async fn test_guild_preview_widget_and_vanity_requests() {
+    let (client, mut requests) = client(vec![
+        json_response(
+            r#"{"id":"1","name":"Test","icon":null,"splash":null,"discovery_splash":null,
+            "approximate_member_count":42,"approximate_presence_count":7,"description":null}"#,
+        ),
+        json_response(r#"{"enabled":true,"channel_id":"2"}"#),
+        json_response(r#"{"enabled":false,"channel_id":null}"#),
+        json_response(r#"{"id":"1","name":"Test","instant_invite":null,"presence_count":7}"#),
+        json_response(r#"{"code":"test","uses":3}"#),
+    ])
+    .await;
+
+    let preview = client.get_guild_preview(Snowflake::new(1)).await.unwrap();
+    assert_eq!(preview.approximate_member_count, 42);
+    let settings = client.get_guild_widget_settings(Snowflake::new(1)).await.unwrap();
+    assert!(settings.enabled);
+    let settings = GuildWidgetSettings { enabled: false, channel_id: None };
+    client.modify_guild_widget(Snowflake::new(1), &settings, None).await.unwrap();
+    let widget = client.get_guild_widget(Snowflake::new(1)).await.unwrap();
+    assert_eq!(widget.presence_count, 7);
+    let vanity = client.get_guild_vanity_url(Snowflake::new(1)).await.unwrap();
+    assert_eq!(vanity.code.as_deref(), Some("test"));
+
+    let mut requests_seen = Vec::new();
+    for _ in 0..5 {
+        requests_seen.push(requests.recv().await.unwrap());
+    }
+    let lines: Vec<&str> = requests_seen.iter().map(|r| request_line(r)).collect();
+    assert_eq!(
+        lines,
+        [
+            "GET /api/v10/guilds/1/preview HTTP/1.1",
+            "GET /api/v10/guilds/1/widget HTTP/1.1",
+            "PATCH /api/v10/guilds/1/widget HTTP/1.1",
+            "GET /api/v10/guilds/1/widget.json HTTP/1.1",
+            "GET /api/v10/guilds/1/vanity-url HTTP/1.1",
+        ]
+    );
+    assert!(requests_seen[2].ends_with(r#"{"channel_id":null,"enabled":false}"#));
+}
@@ -0,0 +1,42 @@
+use discord_rs_core::DiscordError;
+use discord_rs_http::invalid_requests::{InvalidRequestConfig, InvalidRequestTracker};
+use std::time::Duration;
+
+fn tracker(threshold: usize, window: Duration) -> InvalidRequestTracker {
+    InvalidRequestTracker::new(InvalidRequestConfig {
+        window,
+        threshold,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_default_threshold_is_below_discord_ban_limit() {
+    let config = InvalidRequestConfig::default();
+    assert!(config.threshold < 10_000);
+    assert_eq!(config.window, Duration::from_secs(600));
+}
+
+#[test]
+fn test_rejects_once_threshold_is_reached() {
+    let tracker = tracker(3, Duration::from_secs(60));
+    tracker.record();
+    tracker.record();
+    assert!(tracker.check().is_ok());
+
+    tracker.record();
+    assert_eq!(tracker.count(), 3);
+    assert!(matches!(tracker.check(), Err(DiscordError::RateLimit)));
+}
+
+#[test]
+fn test_old_hits_leave_the_window() {
+    let tracker = tracker(2, Duration::from_millis(50));
+    tracker.record();
+    tracker.record();
+    assert!(tracker.check().is_err());
+
+    std::thread::sleep(Duration::from_millis(80));
+    assert_eq!(tracker.count(), 0);
+    assert!(tracker.check().is_ok());
+}
@@ -0,0 +1,86 @@
+use discord_rs_core::Config;
+use discord_rs_http::middleware::{Middleware, RateLimitInfo, RequestInfo, ResponseInfo};
+use discord_rs_http::RestClient;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::Method;
+use std::sync::{Arc, Mutex};
+
+mod common;
+
+use common::{json_response, response, serve};
+
+#[derive(Default, Clone)]
+struct Recorder {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Recorder {
+    fn before_send(&self, request: &RequestInfo<'_>, headers: &mut HeaderMap) {
+        headers.insert("x-trace-id", HeaderValue::from_static("trace-1"));
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("send {} #{}", request.route.template(), request.attempt));
+    }
+
+    fn after_response(&self, _request: &RequestInfo<'_>, response: &ResponseInfo<'_>) {
+        self.events.lock().unwrap().push(format!("response {}", response.status.as_u16()));
+    }
+
+    fn on_ratelimit(&self, _request: &RequestInfo<'_>, ratelimit: &RateLimitInfo) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("ratelimit global={} retry_after={}", ratelimit.global, ratelimit.retry_after));
+    }
+}
+
+#[tokio::test]
+async fn test_hooks_see_every_attempt_and_can_add_headers() {
+    let limited = response("429 Too Many Requests", r#"{"retry_after":0.01,"global":false}"#);
+    let (base, mut requests) = serve(vec![limited, json_response("{}")]).await;
+
+    let recorder = Recorder::default();
+    let client = RestClient::builder(Arc::new(Config::new("token")))
+        .base_url(base)
+        .middleware(recorder.clone())
+        .build()
+        .unwrap();
+
+    client.request(Method::GET, "/channels/7/messages", None, None).await.unwrap();
+
+    assert_eq!(
+        *recorder.events.lock().unwrap(),
+        vec![
+            "send GET:channels/:id/messages #1",
+            "response 429",
+            "ratelimit global=false retry_after=0.01",
+            "send GET:channels/:id/messages #1",
+            "response 200",
+        ]
+    );
+    assert!(requests.recv().await.unwrap().contains("x-trace-id: trace-1"));
+}
+
+#[tokio::test]
+async fn test_multipart_requests_pass_through_middleware() {
+    use discord_rs_core::traits::{FileUpload, Http};
+
+    let (base, _requests) = serve(vec![json_response("{}")]).await;
+    let recorder = Recorder::default();
+    let client = RestClient::builder(Arc::new(Config::new("token")))
+        .base_url(base)
+        .middleware(recorder.clone())
+        .build()
+        .unwrap();
+
+    client
+        .post_multipart("/channels/7/messages", serde_json::json!({}), vec![FileUpload::new("a", vec![1])])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *recorder.events.lock().unwrap(),
+        vec!["send POST:channels/:id/messages #1", "response 200"]
+    );
+}
@@ -0,0 +1,134 @@
+use discord_rs_core::{DiscordError, Snowflake};
+use discord_rs_http::oauth2::{OAuth2Client, Scope};
+use discord_rs_http::{RestClient, TokenType};
+
+mod common;
+
+use common::{json_response, response, serve};
+
+const TOKEN: &str = r#"{"access_token":"at","token_type":"Bearer","expires_in":604800,"refresh_token":"rt","scope":"identify guilds"}"#;
+
+fn oauth(base: &str) -> OAuth2Client {
+    OAuth2Client::new(Snowflake::new(42), "secret")
+        .redirect_uri("https://example.com/callback")
+        .api_base(format!("{}/v10", base))
+}
+
+#[test]
+fn test_authorization_url_includes_scopes_and_state() {
+    let url = oauth("http://localhost")
+        .authorization_url(&[Scope::Identify, Scope::Guilds], Some("xyz"))
+        .unwrap();
+
+    assert_eq!(
+        url,
+        "https://discord.com/oauth2/authorize?response_type=code&client_id=42\
+         &scope=identify+guilds&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&state=xyz"
+    );
+}
+
+#[test]
+fn test_authorization_url_requires_redirect() {
+    let client = OAuth2Client::new(Snowflake::new(42), "secret");
+    assert!(matches!(
+        client.authorization_url(&[Scope::Identify], None),
+        Err(DiscordError::Configuration(_))
+    ));
+}
+
+#[test]
+fn test_debug_output_redacts_secrets() {
+    let client = format!("{:?}", OAuth2Client::new(Snowflake::new(42), "hunter2"));
+    assert!(client.contains("42"));
+    assert!(!client.contains("hunter2"));
+
+    let token: discord_rs_http::oauth2::AccessToken = serde_json::from_str(
+        r#"{"access_token":"s3cr3t-at","token_type":"Bearer","expires_in":604800,"refresh_token":"s3cr3t-rt","scope":"identify"}"#,
+    )
+    .unwrap();
+    let token = format!("{:?}", token);
+    assert!(token.contains("identify"));
+    assert!(!token.contains("s3cr3t"));
+}
+
+#[tokio::test]
+async fn test_exchanges_code_with_basic_auth() {
+    let (base, mut requests) = serve(vec![json_response(TOKEN)]).await;
+
+    let token = oauth(&base).exchange_code("the-code").await.unwrap();
+    assert_eq!(token.access_token, "at");
+    assert_eq!(token.refresh_token.as_deref(), Some("rt"));
+    assert_eq!(token.scopes().collect::<Vec<_>>(), vec!["identify", "guilds"]);
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/v10/oauth2/token "));
+    // base64("42:secret")
+    assert!(request.contains("authorization: Basic NDI6c2VjcmV0"));
+    assert!(request.ends_with(
+        "grant_type=authorization_code&code=the-code&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback"
+    ));
+}
+
+#[tokio::test]
+async fn test_refresh_and_client_credentials_send_their_grant() {
+    let (base, mut requests) = serve(vec![json_response(TOKEN), json_response(TOKEN)]).await;
+    let client = oauth(&base);
+
+    client.refresh("rt").await.unwrap();
+    client.client_credentials(&[Scope::ApplicationsCommandsUpdate]).await.unwrap();
+
+    assert!(requests.recv().await.unwrap().ends_with("grant_type=refresh_token&refresh_token=rt"));
+    assert!(requests
+        .recv()
+        .await
+        .unwrap()
+        .ends_with("grant_type=client_credentials&scope=applications.commands.update"));
+}
+
+#[tokio::test]
+async fn test_invalid_grant_becomes_oauth2_error() {
+    let error = response(
+        "400 Bad Request",
+        r#"{"error":"invalid_grant","error_description":"Invalid \"code\""}"#,
+    );
+    let (base, _requests) = serve(vec![error]).await;
+
+    let err = oauth(&base).exchange_code("bad").await.unwrap_err();
+    assert!(err.api_code().is_none());
+    assert_eq!(err.to_string(), "OAuth2 error: invalid_grant: Invalid \"code\"");
+    match err {
+        DiscordError::OAuth2 { error, description } => {
+            assert_eq!(error, "invalid_grant");
+            assert_eq!(description.as_deref(), Some("Invalid \"code\""));
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_revoke_posts_token() {
+    let (base, mut requests) = serve(vec![json_response("{}")]).await;
+    oauth(&base).revoke("at").await.unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/v10/oauth2/token/revoke "));
+    assert!(request.ends_with("token=at"));
+}
+
+#[tokio::test]
+async fn test_bearer_client_sends_bearer_token() {
+    let (base, mut requests) = serve(vec![json_response("[]")]).await;
+    let client = RestClient::builder(std::sync::Arc::new(discord_rs_core::Config::new("at")))
+        .token_type(TokenType::Bearer)
+        .base_url(base)
+        .build()
+        .unwrap();
+
+    let guilds = client.get_current_user_guilds(&Default::default()).await.unwrap();
+    assert!(guilds.is_empty());
+    assert_eq!(RestClient::bearer("at").unwrap().token_type(), TokenType::Bearer);
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /api/v10/users/@me/guilds "));
+    assert!(request.contains("authorization: Bearer at"));
+}
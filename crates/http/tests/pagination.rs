@@ -0,0 +1,117 @@
+use discord_rs_core::Snowflake;
+use discord_rs_http::pagination::Pagination;
+use discord_rs_model::channel::ArchivedThreadKind;
+use futures::StreamExt;
+
+mod common;
+
+use common::{client, json_response, request_line};
+
+fn bans(ids: &[u64]) -> String {
+    let bans: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            serde_json::json!({
+                "reason": null,
+                "user": { "id": id.to_string(), "username": "u", "discriminator": "0" }
+            })
+        })
+        .collect();
+    json_response(&serde_json::Value::from(bans).to_string())
+}
+
+fn archived_threads(ids: &[u64], has_more: bool) -> String {
+    let threads: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            serde_json::json!({
+                "id": id.to_string(),
+                "type": 11,
+                "thread_metadata": {
+                    "archived": true,
+                    "auto_archive_duration": 60,
+                    "archive_timestamp": format!("2024-01-0{}T00:00:00+00:00", id),
+                    "locked": false
+                }
+            })
+        })
+        .collect();
+    let list = serde_json::json!({ "threads": threads, "members": [], "has_more": has_more });
+    json_response(&list.to_string())
+}
+
+#[tokio::test]
+async fn test_limit_shrinks_the_last_page_and_moves_the_cursor() {
+    // Discord returns bans in ascending id order regardless of the cursor.
+    let first: Vec<u64> = (1001..=2000).collect();
+    let (client, mut requests) = client(vec![bans(&first), bans(&[999, 1000])]).await;
+
+    let ids: Vec<Snowflake> = client
+        .stream_guild_bans(Snowflake::new(1), Pagination::new().limit(1002))
+        .map(|ban| ban.unwrap().user.id)
+        .collect()
+        .await;
+
+    assert_eq!(ids.len(), 1002);
+    assert_eq!(ids[0], Snowflake::new(2000));
+    assert_eq!(ids[1001], Snowflake::new(999));
+
+    let first = requests.recv().await.unwrap();
+    assert_eq!(request_line(&first), "GET /api/v10/guilds/1/bans?limit=1000 HTTP/1.1");
+    let second = requests.recv().await.unwrap();
+    assert_eq!(request_line(&second), "GET /api/v10/guilds/1/bans?before=1001&limit=2 HTTP/1.1");
+}
+
+#[tokio::test]
+async fn test_forward_pages_are_oldest_first_and_stop_on_short_page() {
+    // Returned out of order on purpose; the stream sorts each page.
+    let (client, mut requests) = client(vec![bans(&[12, 11, 13])]).await;
+
+    let ids: Vec<u64> = client
+        .stream_guild_bans(Snowflake::new(1), Pagination::after(Snowflake::new(10)))
+        .map(|ban| u64::from(ban.unwrap().user.id))
+        .collect()
+        .await;
+
+    assert_eq!(ids, vec![11, 12, 13]);
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "GET /api/v10/guilds/1/bans?after=10&limit=1000 HTTP/1.1");
+    assert!(requests.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn test_dropping_the_stream_stops_fetching() {
+    let first: Vec<u64> = (1..=1000).collect();
+    let (client, mut requests) = client(vec![bans(&first), bans(&[])]).await;
+
+    let taken: Vec<_> = client
+        .stream_guild_bans(Snowflake::new(1), Pagination::new())
+        .take(3)
+        .collect()
+        .await;
+
+    assert_eq!(taken.len(), 3);
+    assert!(requests.recv().await.is_some());
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_archived_thread_pages_are_sized_to_max_items() {
+    let (client, mut requests) = client(vec![archived_threads(&[5, 4], true), archived_threads(&[3], true)]).await;
+
+    let ids: Vec<u64> = client
+        .stream_archived_threads(Snowflake::new(2), ArchivedThreadKind::Public, Some(3))
+        .map(|thread| u64::from(thread.unwrap().id))
+        .collect()
+        .await;
+
+    assert_eq!(ids, vec![5, 4, 3]);
+    let first = requests.recv().await.unwrap();
+    assert_eq!(request_line(&first), "GET /api/v10/channels/2/threads/archived/public?limit=3 HTTP/1.1");
+    let second = requests.recv().await.unwrap();
+    assert_eq!(
+        request_line(&second),
+        "GET /api/v10/channels/2/threads/archived/public?before=2024-01-04T00%3A00%3A00%2B00%3A00&limit=1 HTTP/1.1"
+    );
+    assert!(requests.recv().await.is_none());
+}
@@ -0,0 +1,148 @@
+use discord_rs_http::ratelimit::{RateLimitScope, RateLimiter};
+use discord_rs_http::routing::Route;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::Method;
+
+fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (name, value) in pairs {
+        map.insert(*name, HeaderValue::from_str(value).unwrap());
+    }
+    map
+}
+
+#[tokio::test]
+async fn test_routes_with_same_hash_share_state() {
+    let limiter = RateLimiter::new();
+    let edit = Route::new(Method::PATCH, "/channels/1/messages/10");
+    let pin = Route::new(Method::PUT, "/channels/1/pins/10");
+
+    let h = headers(&[
+        ("x-ratelimit-bucket", "abcd1234"),
+        ("x-ratelimit-limit", "5"),
+        ("x-ratelimit-remaining", "3"),
+        ("x-ratelimit-reset-after", "1.5"),
+    ]);
+    limiter.update(&edit, &h).await;
+    limiter.update(&pin, &headers(&[("x-ratelimit-bucket", "abcd1234")])).await;
+
+    assert_eq!(limiter.bucket_hash(&pin).as_deref(), Some("abcd1234"));
+    assert_eq!(limiter.remaining(&edit).await, Some(3));
+    assert_eq!(limiter.remaining(&pin).await, Some(3));
+}
+
+#[tokio::test]
+async fn test_major_parameter_separates_buckets() {
+    let limiter = RateLimiter::new();
+    let first = Route::new(Method::POST, "/channels/1/messages");
+    let second = Route::new(Method::POST, "/channels/2/messages");
+
+    let h = headers(&[
+        ("x-ratelimit-bucket", "msgs"),
+        ("x-ratelimit-limit", "5"),
+        ("x-ratelimit-remaining", "0"),
+        ("x-ratelimit-reset-after", "2"),
+    ]);
+    limiter.update(&first, &h).await;
+
+    assert_eq!(limiter.bucket_hash(&second).as_deref(), Some("msgs"));
+    assert_eq!(limiter.remaining(&first).await, Some(0));
+    assert_eq!(limiter.remaining(&second).await, None);
+}
+
+#[tokio::test]
+async fn test_update_creates_unknown_bucket() {
+    let limiter = RateLimiter::new();
+    let route = Route::new(Method::GET, "/guilds/5/roles");
+    let h = headers(&[("x-ratelimit-limit", "10"), ("x-ratelimit-remaining", "9")]);
+    limiter.update(&route, &h).await;
+    assert_eq!(limiter.remaining(&route).await, Some(9));
+}
+
+#[test]
+fn test_scope_header() {
+    assert_eq!(RateLimitScope::from_headers(&headers(&[("x-ratelimit-scope", "shared")])), Some(RateLimitScope::Shared));
+    assert_eq!(RateLimitScope::from_headers(&headers(&[("x-ratelimit-scope", "global")])), Some(RateLimitScope::Global));
+    assert_eq!(RateLimitScope::from_headers(&HeaderMap::new()), None);
+}
+
+#[tokio::test]
+async fn test_remote_limiters_share_buckets() {
+    use discord_rs_http::ratelimit::service::{RateLimitService, RemoteRateLimiter};
+    use discord_rs_http::ratelimit::RateLimitBackend;
+    use std::time::{Duration, Instant};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    tokio::spawn(RateLimitService::new().serve(listener));
+
+    let first = RemoteRateLimiter::new(addr.clone());
+    let second = RemoteRateLimiter::new(addr);
+    let route = Route::new(Method::POST, "/channels/1/messages");
+
+    first.acquire(&route).await;
+    let h = headers(&[
+        ("x-ratelimit-bucket", "msgs"),
+        ("x-ratelimit-limit", "5"),
+        ("x-ratelimit-remaining", "0"),
+        ("x-ratelimit-reset-after", "0.3"),
+    ]);
+    RateLimitBackend::update(&first, &route, &h).await;
+
+    // The other "process" has never seen a response but must still wait.
+    let start = Instant::now();
+    second.acquire(&route).await;
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn test_remote_limiter_falls_back_when_service_is_down() {
+    use discord_rs_http::ratelimit::service::RemoteRateLimiter;
+    use discord_rs_http::ratelimit::RateLimitBackend;
+    use std::time::Duration;
+
+    // Bind and drop to get a port nothing listens on.
+    let addr = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap();
+    let limiter = RemoteRateLimiter::new(addr.to_string());
+    let route = Route::new(Method::GET, "/guilds/5/roles");
+
+    let calls = async {
+        limiter.acquire(&route).await;
+        let h = headers(&[("x-ratelimit-limit", "10"), ("x-ratelimit-remaining", "9")]);
+        RateLimitBackend::update(&limiter, &route, &h).await;
+    };
+    tokio::time::timeout(Duration::from_secs(2), calls)
+        .await
+        .expect("calls against a dead service should fail fast");
+
+    assert_eq!(limiter.fallback().remaining(&route).await, Some(9));
+}
+
+#[tokio::test]
+async fn test_remote_limiter_sends_route_key_without_path() {
+    use discord_rs_http::ratelimit::service::RemoteRateLimiter;
+    use discord_rs_http::ratelimit::RateLimitBackend;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (read, mut write) = stream.into_split();
+        let line = BufReader::new(read).lines().next_line().await.unwrap().unwrap();
+        write.write_all(b"{\"ok\":true}\n").await.unwrap();
+        line
+    });
+
+    let limiter = RemoteRateLimiter::new(addr);
+    let route = Route::new(Method::POST, "/webhooks/6/s3cr3t-token?wait=true");
+    limiter.acquire(&route).await;
+
+    let line = server.await.unwrap();
+    let request: serde_json::Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(request["op"], "acquire");
+    assert_eq!(request["route"]["template"], "POST:webhooks/:id/:token");
+    assert!(request["route"]["major"].as_str().unwrap().starts_with("webhook:6:"));
+    assert!(!line.contains("s3cr3t"));
+    assert!(!line.contains("wait"));
+}
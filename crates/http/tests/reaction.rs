@@ -0,0 +1,72 @@
+use discord_rs_core::Snowflake;
+use discord_rs_http::pagination::Pagination;
+use discord_rs_model::reaction::{ReactionKind, ReactionType};
+use futures::StreamExt;
+
+mod common;
+
+use common::{client, json_response, request_line, NO_CONTENT};
+
+fn users(ids: impl IntoIterator<Item = u64>) -> String {
+    let users: Vec<_> = ids
+        .into_iter()
+        .map(|id| serde_json::json!({ "id": id.to_string(), "username": "u", "discriminator": "0" }))
+        .collect();
+    json_response(&serde_json::Value::from(users).to_string())
+}
+
+#[tokio::test]
+async fn test_reaction_emojis_are_encoded_in_the_path() {
+    let (client, mut requests) = client(vec![NO_CONTENT.to_string(), NO_CONTENT.to_string()]).await;
+
+    client
+        .create_reaction(Snowflake::new(1), Snowflake::new(2), &ReactionType::unicode("🔥"))
+        .await
+        .unwrap();
+    client
+        .delete_user_reaction(
+            Snowflake::new(1),
+            Snowflake::new(2),
+            &ReactionType::custom("blob", Snowflake::new(3)),
+            Snowflake::new(4),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        request_line(&requests.recv().await.unwrap()),
+        "PUT /api/v10/channels/1/messages/2/reactions/%F0%9F%94%A5/@me HTTP/1.1"
+    );
+    assert_eq!(
+        request_line(&requests.recv().await.unwrap()),
+        "DELETE /api/v10/channels/1/messages/2/reactions/blob:3/4 HTTP/1.1"
+    );
+}
+
+#[tokio::test]
+async fn test_stream_reactions_pages_forward_by_kind() {
+    let (client, mut requests) = client(vec![users(1..=100), users([101, 102])]).await;
+
+    let ids: Vec<Snowflake> = client
+        .stream_reactions(
+            Snowflake::new(1),
+            Snowflake::new(2),
+            ReactionType::unicode("👍"),
+            ReactionKind::Burst,
+            Pagination::new(),
+        )
+        .map(|user| user.unwrap().id)
+        .collect()
+        .await;
+
+    assert_eq!(ids.len(), 102);
+    assert_eq!(ids[0], Snowflake::new(1));
+    assert_eq!(
+        request_line(&requests.recv().await.unwrap()),
+        "GET /api/v10/channels/1/messages/2/reactions/%F0%9F%91%8D?after=0&limit=100&type=1 HTTP/1.1"
+    );
+    assert_eq!(
+        request_line(&requests.recv().await.unwrap()),
+        "GET /api/v10/channels/1/messages/2/reactions/%F0%9F%91%8D?after=100&limit=100&type=1 HTTP/1.1"
+    );
+}
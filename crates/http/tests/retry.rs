@@ -0,0 +1,47 @@
+use discord_rs_http::retry::{is_idempotent, is_retryable_status, RetryPolicy};
+use reqwest::{Method, StatusCode};
+use std::time::Duration;
+
+#[test]
+fn test_only_gateway_style_statuses_are_retryable() {
+    for code in [500, 502, 503, 504] {
+        assert!(is_retryable_status(StatusCode::from_u16(code).unwrap()));
+    }
+    for code in [400, 401, 403, 404, 429, 501] {
+        assert!(!is_retryable_status(StatusCode::from_u16(code).unwrap()));
+    }
+}
+
+#[test]
+fn test_post_is_not_retried_unless_enabled() {
+    let policy = RetryPolicy::default();
+    assert!(is_idempotent(&Method::PUT));
+    assert!(!policy.should_retry_status(&Method::POST, StatusCode::BAD_GATEWAY, 1));
+    assert!(policy.should_retry_status(&Method::GET, StatusCode::BAD_GATEWAY, 1));
+
+    let policy = policy.retry_non_idempotent(true);
+    assert!(policy.should_retry_status(&Method::POST, StatusCode::BAD_GATEWAY, 1));
+}
+
+#[test]
+fn test_stops_at_max_attempts() {
+    let policy = RetryPolicy::new().max_attempts(2);
+    assert!(policy.should_retry_status(&Method::GET, StatusCode::SERVICE_UNAVAILABLE, 1));
+    assert!(!policy.should_retry_status(&Method::GET, StatusCode::SERVICE_UNAVAILABLE, 2));
+    assert!(!RetryPolicy::none().should_retry_status(&Method::GET, StatusCode::SERVICE_UNAVAILABLE, 1));
+}
+
+#[test]
+fn test_backoff_grows_and_is_capped() {
+    let policy = RetryPolicy::new()
+        .base_delay(Duration::from_millis(100))
+        .max_delay(Duration::from_millis(300));
+
+    let first = policy.backoff(1);
+    assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+    let second = policy.backoff(2);
+    assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+    for retry in 3..40 {
+        assert!(policy.backoff(retry) <= Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,175 @@
+use discord_rs_http::ratelimit::RouteKey;
+use discord_rs_http::routing::{with_query, Route};
+use discord_rs_model::message::GetMessages;
+use discord_rs_model::Snowflake;
+use reqwest::Method;
+
+#[test]
+fn test_query_does_not_change_bucket() {
+    let plain = Route::new(Method::GET, "/channels/123/messages");
+    let queried = Route::new(Method::GET, "/channels/123/messages?limit=50&before=456");
+    assert_eq!(plain.bucket_key(), queried.bucket_key());
+}
+
+#[test]
+fn test_message_routes_share_bucket_per_channel() {
+    let a = Route::new(Method::PATCH, "/channels/123/messages/1");
+    let b = Route::new(Method::PATCH, "/channels/123/messages/2");
+    let other = Route::new(Method::PATCH, "/channels/999/messages/1");
+    assert_eq!(a.bucket_key(), b.bucket_key());
+    assert_ne!(a.bucket_key(), other.bucket_key());
+}
+
+#[test]
+fn test_with_query_skips_unset_fields() {
+    let query = GetMessages::new().before(Snowflake(456)).limit(25);
+    let path = with_query("/channels/123/messages", &query).unwrap();
+    assert_eq!(path, "/channels/123/messages?before=456&limit=25");
+
+    let empty = with_query("/channels/123/messages", &GetMessages::new()).unwrap();
+    assert_eq!(empty, "/channels/123/messages");
+}
+
+#[test]
+fn test_with_query_joins_role_lists() {
+    use discord_rs_model::guild::GetGuildPruneCount;
+
+    let query = GetGuildPruneCount {
+        days: Some(14),
+        include_roles: vec![Snowflake(1), Snowflake(2)],
+    };
+    let path = with_query("/guilds/9/prune", &query).unwrap();
+    assert_eq!(path, "/guilds/9/prune?days=14&include_roles=1%2C2");
+}
+
+#[test]
+fn test_route_templates() {
+    use discord_rs_http::routing::MajorParameter;
+
+    let cases: &[(Method, &str, &str, MajorParameter)] = &[
+        (Method::GET, "/gateway/bot", "GET:gateway/bot", MajorParameter::None),
+        (Method::GET, "/users/@me", "GET:users/@me", MajorParameter::None),
+        (Method::GET, "/users/80351110224678912", "GET:users/:id", MajorParameter::None),
+        (Method::POST, "/channels/1/messages", "POST:channels/:id/messages", MajorParameter::Channel(1)),
+        (Method::GET, "/channels/1/messages?limit=5", "GET:channels/:id/messages", MajorParameter::Channel(1)),
+        (Method::PATCH, "/channels/1/messages/2", "PATCH:channels/:id/messages/:id", MajorParameter::Channel(1)),
+        (Method::POST, "/channels/1/messages/bulk-delete", "POST:channels/:id/messages/bulk-delete", MajorParameter::Channel(1)),
+        (Method::PUT, "/channels/1/messages/2/reactions/%F0%9F%91%8D/@me", "PUT:channels/:id/messages/:id/reactions/:emoji/@me", MajorParameter::Channel(1)),
+        (Method::PUT, "/channels/1/messages/2/reactions/blobwave:123456/@me", "PUT:channels/:id/messages/:id/reactions/:emoji/@me", MajorParameter::Channel(1)),
+        (Method::DELETE, "/channels/1/messages/2/reactions/blobwave:123456/99", "DELETE:channels/:id/messages/:id/reactions/:emoji/:id", MajorParameter::Channel(1)),
+        (Method::DELETE, "/channels/1/messages/2/reactions/%F0%9F%91%8D", "DELETE:channels/:id/messages/:id/reactions/:emoji", MajorParameter::Channel(1)),
+        (Method::DELETE, "/channels/1/messages/2/reactions", "DELETE:channels/:id/messages/:id/reactions", MajorParameter::Channel(1)),
+        (Method::PUT, "/channels/1/thread-members/@me", "PUT:channels/:id/thread-members/@me", MajorParameter::Channel(1)),
+        (Method::PATCH, "/guilds/3/members/4", "PATCH:guilds/:id/members/:id", MajorParameter::Guild(3)),
+        (Method::PUT, "/guilds/3/members/4/roles/5", "PUT:guilds/:id/members/:id/roles/:id", MajorParameter::Guild(3)),
+        (Method::GET, "/guilds/3/widget.json", "GET:guilds/:id/widget.json", MajorParameter::Guild(3)),
+        (Method::GET, "/webhooks/6", "GET:webhooks/:id", MajorParameter::webhook(6, None)),
+        (Method::POST, "/webhooks/6/s3cr3t?wait=true", "POST:webhooks/:id/:token", MajorParameter::webhook(6, Some("s3cr3t"))),
+        (Method::PATCH, "/webhooks/6/s3cr3t/messages/@original", "PATCH:webhooks/:id/:token/messages/@original", MajorParameter::webhook(6, Some("s3cr3t"))),
+        (Method::PATCH, "/webhooks/6/123abc/messages/7", "PATCH:webhooks/:id/:token/messages/:id", MajorParameter::webhook(6, Some("123abc"))),
+        (Method::POST, "/interactions/8/aW50ZXJhY3Rpb24/callback", "POST:interactions/:id/:token/callback", MajorParameter::Interaction(8)),
+        (Method::PUT, "/applications/9/guilds/3/commands", "PUT:applications/:id/guilds/:id/commands", MajorParameter::None),
+    ];
+
+    for (method, path, template, major) in cases {
+        let route = Route::new(method.clone(), *path);
+        assert_eq!(route.template(), *template, "template of {} {}", method, path);
+        assert_eq!(&route.major_parameter(), major, "major parameter of {} {}", method, path);
+    }
+}
+
+#[test]
+fn test_tokens_never_appear_in_bucket_keys() {
+    let webhook = Route::new(Method::POST, "/webhooks/6/s3cr3t-token");
+    assert!(!webhook.template().contains("s3cr3t"));
+    assert!(!webhook.bucket_key().contains("s3cr3t"));
+    assert!(!webhook.major_parameter().to_string().contains("s3cr3t"));
+
+    // Different tokens for the same webhook are still limited separately.
+    let other = Route::new(Method::POST, "/webhooks/6/other-token");
+    assert_ne!(webhook.bucket_key(), other.bucket_key());
+    assert_eq!(
+        webhook.bucket_key(),
+        Route::new(Method::POST, "/webhooks/6/s3cr3t-token?wait=true").bucket_key()
+    );
+
+    let a = Route::new(Method::POST, "/interactions/8/token-a/callback");
+    let b = Route::new(Method::POST, "/interactions/9/token-b/callback");
+    assert_ne!(a.bucket_key(), b.bucket_key());
+    assert_eq!(a.bucket_key(), "POST:interactions/:id/:token/callback@interaction:8");
+    assert!(!a.bucket_key().contains("token-a"));
+}
+
+#[test]
+fn test_old_message_delete_bucket() {
+    let now_ms: u64 = 1_700_000_000_000;
+    let snowflake_at = |ms: u64| (ms - 1_420_070_400_000) << 22;
+
+    let fresh = snowflake_at(now_ms - 60 * 60 * 1000);
+    let old = snowflake_at(now_ms - 15 * 24 * 60 * 60 * 1000);
+
+    let fresh_route = Route::new(Method::DELETE, format!("/channels/1/messages/{}", fresh));
+    let old_route = Route::new(Method::DELETE, format!("/channels/1/messages/{}", old));
+    assert!(!fresh_route.is_old_message_delete(now_ms));
+    assert!(old_route.is_old_message_delete(now_ms));
+
+    // Only deletes are affected.
+    let edit = Route::new(Method::PATCH, format!("/channels/1/messages/{}", old));
+    assert!(!edit.is_old_message_delete(now_ms));
+
+    assert_eq!(fresh_route.template_at(now_ms), "DELETE:channels/:id/messages/:id");
+    assert_eq!(old_route.template_at(now_ms), "DELETE:channels/:id/messages/:id:old");
+    assert_eq!(old_route.bucket_key_at(now_ms), "DELETE:channels/:id/messages/:id:old@channel:1");
+    assert_eq!(RouteKey::at(&old_route, now_ms).bucket_key(), old_route.bucket_key_at(now_ms));
+    // Without a clock, the split isn't applied.
+    assert_eq!(old_route.template(), fresh_route.template());
+}
+
+#[test]
+fn test_endpoint_routes() {
+    use discord_rs_http::routing::{Endpoint, MajorParameter};
+    use discord_rs_model::reaction::ReactionType;
+
+    let unicode = ReactionType::unicode("👍");
+    let custom = ReactionType::custom("blobwave", Snowflake(123456));
+    let (channel_id, message_id, guild_id, user_id) = (Snowflake(1), Snowflake(2), Snowflake(3), Snowflake(4));
+    let (application_id, webhook_id) = (Snowflake(9), Snowflake(6));
+
+    let cases: Vec<(Method, Endpoint, &str, &str, MajorParameter)> = vec![
+        (Method::GET, Endpoint::GatewayBot, "/gateway/bot", "GET:gateway/bot", MajorParameter::None),
+        (Method::GET, Endpoint::CurrentUser, "/users/@me", "GET:users/@me", MajorParameter::None),
+        (Method::GET, Endpoint::User { user_id }, "/users/4", "GET:users/:id", MajorParameter::None),
+        (Method::DELETE, Endpoint::CurrentUserGuild { guild_id }, "/users/@me/guilds/3", "DELETE:users/@me/guilds/:id", MajorParameter::None),
+        (Method::POST, Endpoint::ChannelMessages { channel_id }, "/channels/1/messages", "POST:channels/:id/messages", MajorParameter::Channel(1)),
+        (Method::PATCH, Endpoint::ChannelMessage { channel_id, message_id }, "/channels/1/messages/2", "PATCH:channels/:id/messages/:id", MajorParameter::Channel(1)),
+        (Method::POST, Endpoint::BulkDeleteMessages { channel_id }, "/channels/1/messages/bulk-delete", "POST:channels/:id/messages/bulk-delete", MajorParameter::Channel(1)),
+        (Method::PUT, Endpoint::OwnReaction { channel_id, message_id, emoji: &unicode }, "/channels/1/messages/2/reactions/%F0%9F%91%8D/@me", "PUT:channels/:id/messages/:id/reactions/:emoji/@me", MajorParameter::Channel(1)),
+        (Method::PUT, Endpoint::OwnReaction { channel_id, message_id, emoji: &custom }, "/channels/1/messages/2/reactions/blobwave:123456/@me", "PUT:channels/:id/messages/:id/reactions/:emoji/@me", MajorParameter::Channel(1)),
+        (Method::DELETE, Endpoint::UserReaction { channel_id, message_id, emoji: &custom, user_id }, "/channels/1/messages/2/reactions/blobwave:123456/4", "DELETE:channels/:id/messages/:id/reactions/:emoji/:id", MajorParameter::Channel(1)),
+        (Method::DELETE, Endpoint::MessageReaction { channel_id, message_id, emoji: &unicode }, "/channels/1/messages/2/reactions/%F0%9F%91%8D", "DELETE:channels/:id/messages/:id/reactions/:emoji", MajorParameter::Channel(1)),
+        (Method::DELETE, Endpoint::MessageReactions { channel_id, message_id }, "/channels/1/messages/2/reactions", "DELETE:channels/:id/messages/:id/reactions", MajorParameter::Channel(1)),
+        (Method::PUT, Endpoint::CurrentThreadMember { thread_id: channel_id }, "/channels/1/thread-members/@me", "PUT:channels/:id/thread-members/@me", MajorParameter::Channel(1)),
+        (Method::GET, Endpoint::JoinedPrivateArchivedThreads { channel_id }, "/channels/1/users/@me/threads/archived/private", "GET:channels/:id/users/@me/threads/archived/private", MajorParameter::Channel(1)),
+        (Method::PATCH, Endpoint::GuildMember { guild_id, user_id }, "/guilds/3/members/4", "PATCH:guilds/:id/members/:id", MajorParameter::Guild(3)),
+        (Method::PUT, Endpoint::GuildMemberRole { guild_id, user_id, role_id: Snowflake(5) }, "/guilds/3/members/4/roles/5", "PUT:guilds/:id/members/:id/roles/:id", MajorParameter::Guild(3)),
+        (Method::GET, Endpoint::GuildWidget { guild_id }, "/guilds/3/widget.json", "GET:guilds/:id/widget.json", MajorParameter::Guild(3)),
+        (Method::POST, Endpoint::GuildBulkBan { guild_id }, "/guilds/3/bulk-ban", "POST:guilds/:id/bulk-ban", MajorParameter::Guild(3)),
+        (Method::PATCH, Endpoint::AutoModerationRule { guild_id, rule_id: Snowflake(7) }, "/guilds/3/auto-moderation/rules/7", "PATCH:guilds/:id/auto-moderation/rules/:id", MajorParameter::Guild(3)),
+        (Method::PUT, Endpoint::GuildCommands { application_id, guild_id }, "/applications/9/guilds/3/commands", "PUT:applications/:id/guilds/:id/commands", MajorParameter::None),
+        (Method::PUT, Endpoint::CommandPermissions { application_id, guild_id, command_id: Snowflake(8) }, "/applications/9/guilds/3/commands/8/permissions", "PUT:applications/:id/guilds/:id/commands/:id/permissions", MajorParameter::None),
+        (Method::GET, Endpoint::StickerPacks, "/sticker-packs", "GET:sticker-packs", MajorParameter::None),
+        (Method::GET, Endpoint::Webhook { webhook_id }, "/webhooks/6", "GET:webhooks/:id", MajorParameter::webhook(6, None)),
+        (Method::POST, Endpoint::WebhookWithToken { webhook_id, token: "s3cr3t" }, "/webhooks/6/s3cr3t", "POST:webhooks/:id/:token", MajorParameter::webhook(6, Some("s3cr3t"))),
+        (Method::PATCH, Endpoint::WebhookMessage { webhook_id, token: "s3cr3t", message_id }, "/webhooks/6/s3cr3t/messages/2", "PATCH:webhooks/:id/:token/messages/:id", MajorParameter::webhook(6, Some("s3cr3t"))),
+        (Method::PATCH, Endpoint::OriginalInteractionResponse { application_id, token: "s3cr3t" }, "/webhooks/9/s3cr3t/messages/@original", "PATCH:webhooks/:id/:token/messages/@original", MajorParameter::webhook(9, Some("s3cr3t"))),
+        (Method::POST, Endpoint::InteractionCallback { interaction_id: Snowflake(8), token: "s3cr3t" }, "/interactions/8/s3cr3t/callback", "POST:interactions/:id/:token/callback", MajorParameter::Interaction(8)),
+    ];
+
+    for (method, endpoint, path, template, major) in cases {
+        assert_eq!(endpoint.path(), path);
+        let route = endpoint.route(method);
+        assert_eq!(route.template(), template, "template of {}", path);
+        assert_eq!(route.major_parameter(), major, "major parameter of {}", path);
+        assert!(!route.bucket_key().contains("s3cr3t"), "bucket key of {}", path);
+    }
+}
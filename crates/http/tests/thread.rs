@@ -0,0 +1,164 @@
+use discord_rs_core::Snowflake;
+use discord_rs_model::channel::{
+    ArchivedThreadKind, ChannelType, GetThreadMembers, ListArchivedThreads, StartForumThread,
+    StartThreadFromMessage, StartThreadWithoutMessage, ThreadList,
+};
+
+mod common;
+
+use common::{client, json_response, request_line, NO_CONTENT};
+
+const THREAD: &str = r#"{"id":"7","type":11,"guild_id":"1","parent_id":"2","name":"Plans"}"#;
+const THREAD_MEMBER: &str = r#"{"id":"7","user_id":"3","join_timestamp":"2024-01-01T00:00:00+00:00","flags":0}"#;
+
+fn archived(ids: &[u64], has_more: bool) -> String {
+    let threads: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            serde_json::json!({
+                "id": id.to_string(),
+                "type": 11,
+                "thread_metadata": {
+                    "archived": true,
+                    "auto_archive_duration": 1440,
+                    "archive_timestamp": format!("2024-01-0{}T00:00:00+00:00", id),
+                    "locked": false
+                }
+            })
+        })
+        .collect();
+    let list = serde_json::json!({ "threads": threads, "members": [], "has_more": has_more });
+    json_response(&list.to_string())
+}
+
+#[tokio::test]
+async fn test_start_threads_send_their_bodies() {
+    let (client, mut requests) = client(vec![json_response(THREAD), json_response(THREAD)]).await;
+
+    let mut from_message = StartThreadFromMessage::new("Plans");
+    from_message.auto_archive_duration = Some(60);
+    let thread = client
+        .start_thread_from_message(Snowflake::new(2), Snowflake::new(5), &from_message, Some("split"))
+        .await
+        .unwrap();
+    assert_eq!(thread.kind, ChannelType::PublicThread);
+
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "POST /api/v10/channels/2/messages/5/threads HTTP/1.1");
+    assert!(request.to_lowercase().contains("x-audit-log-reason: split"));
+    assert!(request.ends_with(r#"{"auto_archive_duration":60,"name":"Plans"}"#));
+
+    let mut without_message = StartThreadWithoutMessage::new("Secret", ChannelType::PrivateThread);
+    without_message.invitable = Some(false);
+    client
+        .start_thread_without_message(Snowflake::new(2), &without_message, None)
+        .await
+        .unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "POST /api/v10/channels/2/threads HTTP/1.1");
+    assert!(request.ends_with(r#"{"invitable":false,"name":"Secret","type":12}"#));
+}
+
+#[tokio::test]
+async fn test_start_forum_thread_sends_message_and_tags() {
+    let (client, mut requests) = client(vec![json_response(THREAD)]).await;
+
+    let post = StartForumThread::new("Bug report", serde_json::json!({ "content": "It broke" }))
+        .tag(Snowflake::new(8));
+    client.start_forum_thread(Snowflake::new(2), &post, None).await.unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request_line(&request), "POST /api/v10/channels/2/threads HTTP/1.1");
+    assert!(request.ends_with(r#"{"applied_tags":["8"],"message":{"content":"It broke"},"name":"Bug report"}"#));
+}
+
+#[tokio::test]
+async fn test_thread_membership_requests() {
+    let (client, mut requests) = client(vec![
+        NO_CONTENT.to_string(),
+        NO_CONTENT.to_string(),
+        NO_CONTENT.to_string(),
+        NO_CONTENT.to_string(),
+        json_response(THREAD_MEMBER),
+        json_response(&format!("[{}]", THREAD_MEMBER)),
+    ])
+    .await;
+
+    let thread = Snowflake::new(7);
+    client.join_thread(thread).await.unwrap();
+    client.leave_thread(thread).await.unwrap();
+    client.add_thread_member(thread, Snowflake::new(3)).await.unwrap();
+    client.remove_thread_member(thread, Snowflake::new(3)).await.unwrap();
+    let member = client.get_thread_member(thread, Snowflake::new(3), true).await.unwrap();
+    assert_eq!(member.user_id, Some(Snowflake::new(3)));
+    let query = GetThreadMembers {
+        with_member: Some(true),
+        after: Some(Snowflake::new(3)),
+        limit: Some(50),
+    };
+    let members = client.get_thread_members(thread, &query).await.unwrap();
+    assert_eq!(members.len(), 1);
+
+    let mut lines = Vec::new();
+    for _ in 0..6 {
+        lines.push(request_line(&requests.recv().await.unwrap()).to_string());
+    }
+    assert_eq!(
+        lines,
+        [
+            "PUT /api/v10/channels/7/thread-members/@me HTTP/1.1",
+            "DELETE /api/v10/channels/7/thread-members/@me HTTP/1.1",
+            "PUT /api/v10/channels/7/thread-members/3 HTTP/1.1",
+            "DELETE /api/v10/channels/7/thread-members/3 HTTP/1.1",
+            "GET /api/v10/channels/7/thread-members/3?with_member=true HTTP/1.1",
+            "GET /api/v10/channels/7/thread-members?after=3&limit=50&with_member=true HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_list_active_and_archived_threads() {
+    let (client, mut requests) = client(vec![
+        json_response(&format!(r#"{{"threads":[{}],"members":[]}}"#, THREAD)),
+        archived(&[3, 2], true),
+        archived(&[1], false),
+        archived(&[9], false),
+    ])
+    .await;
+
+    let active = client.get_active_guild_threads(Snowflake::new(1)).await.unwrap();
+    assert_eq!(active.threads.len(), 1);
+    assert!(!active.has_more);
+
+    let query = ListArchivedThreads { before: None, limit: Some(2) };
+    let page: ThreadList = client
+        .get_archived_threads(Snowflake::new(2), ArchivedThreadKind::Public, &query)
+        .await
+        .unwrap();
+    let next = query.next_page(ArchivedThreadKind::Public, &page).unwrap();
+    let last = client
+        .get_archived_threads(Snowflake::new(2), ArchivedThreadKind::Public, &next)
+        .await
+        .unwrap();
+    assert!(query.next_page(ArchivedThreadKind::Public, &last).is_none());
+
+    client
+        .get_archived_threads(Snowflake::new(2), ArchivedThreadKind::JoinedPrivate, &ListArchivedThreads::default())
+        .await
+        .unwrap();
+
+    let mut lines = Vec::new();
+    for _ in 0..4 {
+        lines.push(request_line(&requests.recv().await.unwrap()).to_string());
+    }
+    assert_eq!(
+        lines,
+        [
+            "GET /api/v10/guilds/1/threads/active HTTP/1.1",
+            "GET /api/v10/channels/2/threads/archived/public?limit=2 HTTP/1.1",
+            "GET /api/v10/channels/2/threads/archived/public?before=2024-01-02T00%3A00%3A00%2B00%3A00&limit=2 HTTP/1.1",
+            "GET /api/v10/channels/2/users/@me/threads/archived/private HTTP/1.1",
+        ]
+    );
+}
@@ -0,0 +1,181 @@
+use discord_rs_core::traits::FileUpload;
+use discord_rs_core::{Config, DiscordError, Snowflake};
+use discord_rs_http::{RestClient, WebhookClient};
+use discord_rs_model::webhook::{
+    CreateWebhook, ExecuteWebhook, ExecuteWebhookQuery, ModifyWebhook, Webhook, WebhookType,
+};
+use std::sync::Arc;
+
+mod common;
+
+use common::{json_response, serve, NO_CONTENT};
+
+const MESSAGE: &str = r#"{"id":"5","channel_id":"6","author":{"id":"1","username":"hook","discriminator":"0000"},
+"content":"hi","timestamp":"2024-01-01T00:00:00+00:00","edited_timestamp":null,"tts":false,
+"mention_everyone":false,"webhook_id":"1","type":0}"#;
+
+const WEBHOOK: &str = r#"{"id":"1","type":1,"guild_id":"2","channel_id":"6","name":"Alerts","avatar":null,"token":"tok","application_id":null}"#;
+
+fn rest(base: String) -> RestClient {
+    RestClient::builder(Arc::new(Config::new("bot-token")))
+        .base_url(base)
+        .build()
+        .unwrap()
+}
+
+async fn webhook(responses: Vec<String>) -> (WebhookClient, tokio::sync::mpsc::UnboundedReceiver<String>) {
+    let (base, requests) = serve(responses).await;
+    let rest = RestClient::builder(Arc::new(Config::new("bot-token")))
+        .base_url(base)
+        .build()
+        .unwrap();
+    (WebhookClient::with_rest(&rest, Snowflake::new(1), "tok"), requests)
+}
+
+#[test]
+fn test_parses_webhook_urls() {
+    for url in [
+        "https://discord.com/api/webhooks/123/abc-DEF",
+        "https://canary.discord.com/api/v10/webhooks/123/abc-DEF",
+        "https://discordapp.com/api/webhooks/123/abc-DEF/",
+    ] {
+        let client = WebhookClient::from_url(url).unwrap();
+        assert_eq!(client.id(), Snowflake::new(123));
+        assert_eq!(client.token(), "abc-DEF");
+    }
+
+    for url in ["https://discord.com/api/webhooks/123", "https://discord.com/api/webhooks/x/y", "nope"] {
+        assert!(matches!(WebhookClient::from_url(url), Err(DiscordError::Validation(_))));
+    }
+}
+
+#[tokio::test]
+async fn test_execute_without_wait_sends_no_authorization() {
+    let (client, mut requests) = webhook(vec![NO_CONTENT.to_string()]).await;
+
+    let message = ExecuteWebhook::new().content("hi").username("Alerts");
+    let created = client.execute(&message, &ExecuteWebhookQuery::default()).await.unwrap();
+    assert!(created.is_none());
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/v10/webhooks/1/tok "));
+    assert!(!request.to_lowercase().contains("authorization:"));
+    assert!(request.ends_with(r#"{"content":"hi","username":"Alerts"}"#));
+}
+
+#[tokio::test]
+async fn test_execute_with_wait_into_thread_returns_message() {
+    let (client, mut requests) = webhook(vec![json_response(MESSAGE)]).await;
+
+    let message = ExecuteWebhook::new()
+        .content("hi")
+        .add_file(FileUpload::new("log.txt", b"lines".to_vec()));
+    let query = ExecuteWebhookQuery::wait().thread_id(Snowflake::new(9));
+    let created = client.execute(&message, &query).await.unwrap().unwrap();
+    assert_eq!(created.id, Snowflake::new(5));
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/v10/webhooks/1/tok?thread_id=9&wait=true "));
+    assert!(request.contains("name=\"files[0]\"; filename=\"log.txt\""));
+}
+
+#[tokio::test]
+async fn test_delete_message_in_thread() {
+    let (client, mut requests) = webhook(vec![NO_CONTENT.to_string()]).await;
+
+    client.delete_message(Snowflake::new(5), Some(Snowflake::new(9))).await.unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("DELETE /api/v10/webhooks/1/tok/messages/5?thread_id=9 "));
+}
+
+#[tokio::test]
+async fn test_execute_creates_tagged_forum_post() {
+    let (client, mut requests) = webhook(vec![json_response(MESSAGE)]).await;
+
+    let message = ExecuteWebhook::new()
+        .content("hi")
+        .thread_name("Deploy 42")
+        .applied_tags(vec![Snowflake::new(7)]);
+    client.execute(&message, &ExecuteWebhookQuery::wait()).await.unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.ends_with(r#"{"applied_tags":["7"],"content":"hi","thread_name":"Deploy 42"}"#));
+}
+
+#[tokio::test]
+async fn test_create_webhook_and_execute_it() {
+    let (base, mut requests) = serve(vec![json_response(WEBHOOK), NO_CONTENT.to_string()]).await;
+    let rest = rest(base);
+
+    let webhook = rest
+        .create_webhook(Snowflake::new(6), &CreateWebhook::new("Alerts"), Some("ops"))
+        .await
+        .unwrap();
+    assert_eq!(webhook.kind, WebhookType::Incoming);
+    assert_eq!(
+        webhook.execute_url().as_deref(),
+        Some("https://discord.com/api/webhooks/1/tok")
+    );
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/v10/channels/6/webhooks "));
+    assert!(request.to_lowercase().contains("x-audit-log-reason: ops"));
+    assert!(request.ends_with(r#"{"name":"Alerts"}"#));
+
+    let client = WebhookClient::from_webhook(&rest, &webhook).unwrap();
+    client
+        .execute(&ExecuteWebhook::new().content("up"), &ExecuteWebhookQuery::default())
+        .await
+        .unwrap();
+    assert!(requests.recv().await.unwrap().starts_with("POST /api/v10/webhooks/1/tok "));
+}
+
+#[test]
+fn test_follower_webhooks_have_no_client() {
+    let webhook: Webhook = serde_json::from_str(
+        r#"{"id":"3","type":2,"channel_id":"6","name":"News","avatar":null,
+        "source_guild":{"id":"8","name":"Upstream","icon":null},"source_channel":{"id":"4","name":"announcements"}}"#,
+    )
+    .unwrap();
+    let rest = RestClient::new(Arc::new(Config::new("bot-token"))).unwrap();
+
+    assert_eq!(webhook.kind, WebhookType::ChannelFollower);
+    assert_eq!(webhook.source_channel.as_ref().unwrap().name, "announcements");
+    assert!(webhook.execute_url().is_none());
+    assert!(matches!(
+        WebhookClient::from_webhook(&rest, &webhook),
+        Err(DiscordError::Validation(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_modify_with_token_drops_channel_and_authorization() {
+    let (base, mut requests) = serve(vec![json_response(WEBHOOK)]).await;
+
+    let modify = ModifyWebhook::new().name("Renamed").channel_id(Snowflake::new(9));
+    rest(base)
+        .modify_webhook_with_token(Snowflake::new(1), "tok", &modify, None)
+        .await
+        .unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("PATCH /api/v10/webhooks/1/tok "));
+    assert!(!request.to_lowercase().contains("authorization:"));
+    assert!(request.ends_with(r#"{"name":"Renamed"}"#));
+}
+
+#[tokio::test]
+async fn test_follow_announcement_channel() {
+    let (base, mut requests) = serve(vec![json_response(r#"{"channel_id":"4","webhook_id":"3"}"#)]).await;
+
+    let followed = rest(base)
+        .follow_announcement_channel(Snowflake::new(4), Snowflake::new(6), None)
+        .await
+        .unwrap();
+
+    assert_eq!(followed.webhook_id, Snowflake::new(3));
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /api/v10/channels/4/followers "));
+    assert!(request.ends_with(r#"{"webhook_channel_id":"6"}"#));
+}
@@ -15,3 +15,15 @@ pub struct Attachment {
     #[serde(default)]
     pub ephemeral: bool,
 }
+
+/// Attachment entry of a message create/edit payload. New uploads use the
+/// index of their `files[n]` part as `id`; existing attachments use their
+/// real id and are kept on edit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialAttachment {
+    pub id: Snowflake,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
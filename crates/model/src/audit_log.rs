@@ -0,0 +1,517 @@
+//! Guild audit logs: `GET /guilds/{guild.id}/audit-logs` and the
+//! `GUILD_AUDIT_LOG_ENTRY_CREATE` event.
+
+use discord_rs_core::Snowflake;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::auto_moderation::AutoModerationRule;
+use crate::channel::Channel;
+use crate::interaction::ApplicationCommand;
+use crate::user::User;
+use crate::webhook::Webhook;
+
+/// One page of a guild's audit log, with the objects its entries refer to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLog {
+    #[serde(default)]
+    pub application_commands: Vec<ApplicationCommand>,
+    #[serde(default)]
+    pub audit_log_entries: Vec<AuditLogEntry>,
+    #[serde(default)]
+    pub auto_moderation_rules: Vec<AutoModerationRule>,
+    #[serde(default)]
+    pub guild_scheduled_events: Vec<Value>,
+    #[serde(default)]
+    pub integrations: Vec<Value>,
+    #[serde(default)]
+    pub threads: Vec<Channel>,
+    #[serde(default)]
+    pub users: Vec<User>,
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+}
+
+impl AuditLog {
+    /// Looks up the user behind an entry's `user_id` or `target_id`.
+    pub fn user(&self, id: Snowflake) -> Option<&User> {
+        self.users.iter().find(|u| u.id == id)
+    }
+
+    pub fn webhook(&self, id: Snowflake) -> Option<&Webhook> {
+        self.webhooks.iter().find(|w| w.id == id)
+    }
+
+    pub fn thread(&self, id: Snowflake) -> Option<&Channel> {
+        self.threads.iter().find(|t| t.id == id)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub id: Snowflake,
+    /// Only set in `GUILD_AUDIT_LOG_ENTRY_CREATE`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Snowflake>,
+    pub target_id: Option<Snowflake>,
+    /// Who made the change; `None` for some automated actions.
+    pub user_id: Option<Snowflake>,
+    pub action_type: AuditLogEvent,
+    #[serde(default)]
+    pub changes: Vec<AuditLogChange>,
+    pub options: Option<AuditLogEntryInfo>,
+    pub reason: Option<String>,
+}
+
+impl AuditLogEntry {
+    pub fn change(&self, key: &AuditLogChangeKey) -> Option<&AuditLogChange> {
+        self.changes.iter().find(|c| &c.key == key)
+    }
+}
+
+/// Action type of an audit log entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum AuditLogEvent {
+    GuildUpdate = 1,
+    ChannelCreate = 10,
+    ChannelUpdate = 11,
+    ChannelDelete = 12,
+    ChannelOverwriteCreate = 13,
+    ChannelOverwriteUpdate = 14,
+    ChannelOverwriteDelete = 15,
+    MemberKick = 20,
+    MemberPrune = 21,
+    MemberBanAdd = 22,
+    MemberBanRemove = 23,
+    MemberUpdate = 24,
+    MemberRoleUpdate = 25,
+    MemberMove = 26,
+    MemberDisconnect = 27,
+    BotAdd = 28,
+    RoleCreate = 30,
+    RoleUpdate = 31,
+    RoleDelete = 32,
+    InviteCreate = 40,
+    InviteUpdate = 41,
+    InviteDelete = 42,
+    WebhookCreate = 50,
+    WebhookUpdate = 51,
+    WebhookDelete = 52,
+    EmojiCreate = 60,
+    EmojiUpdate = 61,
+    EmojiDelete = 62,
+    MessageDelete = 72,
+    MessageBulkDelete = 73,
+    MessagePin = 74,
+    MessageUnpin = 75,
+    IntegrationCreate = 80,
+    IntegrationUpdate = 81,
+    IntegrationDelete = 82,
+    StageInstanceCreate = 83,
+    StageInstanceUpdate = 84,
+    StageInstanceDelete = 85,
+    StickerCreate = 90,
+    StickerUpdate = 91,
+    StickerDelete = 92,
+    GuildScheduledEventCreate = 100,
+    GuildScheduledEventUpdate = 101,
+    GuildScheduledEventDelete = 102,
+    ThreadCreate = 110,
+    ThreadUpdate = 111,
+    ThreadDelete = 112,
+    ApplicationCommandPermissionUpdate = 121,
+    SoundboardSoundCreate = 130,
+    SoundboardSoundUpdate = 131,
+    SoundboardSoundDelete = 132,
+    AutoModerationRuleCreate = 140,
+    AutoModerationRuleUpdate = 141,
+    AutoModerationRuleDelete = 142,
+    AutoModerationBlockMessage = 143,
+    AutoModerationFlagToChannel = 144,
+    AutoModerationUserCommunicationDisabled = 145,
+    CreatorMonetizationRequestCreated = 150,
+    CreatorMonetizationTermsAccepted = 151,
+    OnboardingPromptCreate = 163,
+    OnboardingPromptUpdate = 164,
+    OnboardingPromptDelete = 165,
+    OnboardingCreate = 166,
+    OnboardingUpdate = 167,
+    HomeSettingsCreate = 190,
+    HomeSettingsUpdate = 191,
+    Unknown(u8),
+}
+
+impl From<u8> for AuditLogEvent {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => AuditLogEvent::GuildUpdate,
+            10 => AuditLogEvent::ChannelCreate,
+            11 => AuditLogEvent::ChannelUpdate,
+            12 => AuditLogEvent::ChannelDelete,
+            13 => AuditLogEvent::ChannelOverwriteCreate,
+            14 => AuditLogEvent::ChannelOverwriteUpdate,
+            15 => AuditLogEvent::ChannelOverwriteDelete,
+            20 => AuditLogEvent::MemberKick,
+            21 => AuditLogEvent::MemberPrune,
+            22 => AuditLogEvent::MemberBanAdd,
+            23 => AuditLogEvent::MemberBanRemove,
+            24 => AuditLogEvent::MemberUpdate,
+            25 => AuditLogEvent::MemberRoleUpdate,
+            26 => AuditLogEvent::MemberMove,
+            27 => AuditLogEvent::MemberDisconnect,
+            28 => AuditLogEvent::BotAdd,
+            30 => AuditLogEvent::RoleCreate,
+            31 => AuditLogEvent::RoleUpdate,
+            32 => AuditLogEvent::RoleDelete,
+            40 => AuditLogEvent::InviteCreate,
+            41 => AuditLogEvent::InviteUpdate,
+            42 => AuditLogEvent::InviteDelete,
+            50 => AuditLogEvent::WebhookCreate,
+            51 => AuditLogEvent::WebhookUpdate,
+            52 => AuditLogEvent::WebhookDelete,
+            60 => AuditLogEvent::EmojiCreate,
+            61 => AuditLogEvent::EmojiUpdate,
+            62 => AuditLogEvent::EmojiDelete,
+            72 => AuditLogEvent::MessageDelete,
+            73 => AuditLogEvent::MessageBulkDelete,
+            74 => AuditLogEvent::MessagePin,
+            75 => AuditLogEvent::MessageUnpin,
+            80 => AuditLogEvent::IntegrationCreate,
+            81 => AuditLogEvent::IntegrationUpdate,
+            82 => AuditLogEvent::IntegrationDelete,
+            83 => AuditLogEvent::StageInstanceCreate,
+            84 => AuditLogEvent::StageInstanceUpdate,
+            85 => AuditLogEvent::StageInstanceDelete,
+            90 => AuditLogEvent::StickerCreate,
+            91 => AuditLogEvent::StickerUpdate,
+            92 => AuditLogEvent::StickerDelete,
+            100 => AuditLogEvent::GuildScheduledEventCreate,
+            101 => AuditLogEvent::GuildScheduledEventUpdate,
+            102 => AuditLogEvent::GuildScheduledEventDelete,
+            110 => AuditLogEvent::ThreadCreate,
+            111 => AuditLogEvent::ThreadUpdate,
+            112 => AuditLogEvent::ThreadDelete,
+            121 => AuditLogEvent::ApplicationCommandPermissionUpdate,
+            130 => AuditLogEvent::SoundboardSoundCreate,
+            131 => AuditLogEvent::SoundboardSoundUpdate,
+            132 => AuditLogEvent::SoundboardSoundDelete,
+            140 => AuditLogEvent::AutoModerationRuleCreate,
+            141 => AuditLogEvent::AutoModerationRuleUpdate,
+            142 => AuditLogEvent::AutoModerationRuleDelete,
+            143 => AuditLogEvent::AutoModerationBlockMessage,
+            144 => AuditLogEvent::AutoModerationFlagToChannel,
+            145 => AuditLogEvent::AutoModerationUserCommunicationDisabled,
+            150 => AuditLogEvent::CreatorMonetizationRequestCreated,
+            151 => AuditLogEvent::CreatorMonetizationTermsAccepted,
+            163 => AuditLogEvent::OnboardingPromptCreate,
+            164 => AuditLogEvent::OnboardingPromptUpdate,
+            165 => AuditLogEvent::OnboardingPromptDelete,
+            166 => AuditLogEvent::OnboardingCreate,
+            167 => AuditLogEvent::OnboardingUpdate,
+            190 => AuditLogEvent::HomeSettingsCreate,
+            191 => AuditLogEvent::HomeSettingsUpdate,
+            _ => AuditLogEvent::Unknown(v),
+        }
+    }
+}
+
+impl From<AuditLogEvent> for u8 {
+    fn from(v: AuditLogEvent) -> Self {
+        match v {
+            AuditLogEvent::GuildUpdate => 1,
+            AuditLogEvent::ChannelCreate => 10,
+            AuditLogEvent::ChannelUpdate => 11,
+            AuditLogEvent::ChannelDelete => 12,
+            AuditLogEvent::ChannelOverwriteCreate => 13,
+            AuditLogEvent::ChannelOverwriteUpdate => 14,
+            AuditLogEvent::ChannelOverwriteDelete => 15,
+            AuditLogEvent::MemberKick => 20,
+            AuditLogEvent::MemberPrune => 21,
+            AuditLogEvent::MemberBanAdd => 22,
+            AuditLogEvent::MemberBanRemove => 23,
+            AuditLogEvent::MemberUpdate => 24,
+            AuditLogEvent::MemberRoleUpdate => 25,
+            AuditLogEvent::MemberMove => 26,
+            AuditLogEvent::MemberDisconnect => 27,
+            AuditLogEvent::BotAdd => 28,
+            AuditLogEvent::RoleCreate => 30,
+            AuditLogEvent::RoleUpdate => 31,
+            AuditLogEvent::RoleDelete => 32,
+            AuditLogEvent::InviteCreate => 40,
+            AuditLogEvent::InviteUpdate => 41,
+            AuditLogEvent::InviteDelete => 42,
+            AuditLogEvent::WebhookCreate => 50,
+            AuditLogEvent::WebhookUpdate => 51,
+            AuditLogEvent::WebhookDelete => 52,
+            AuditLogEvent::EmojiCreate => 60,
+            AuditLogEvent::EmojiUpdate => 61,
+            AuditLogEvent::EmojiDelete => 62,
+            AuditLogEvent::MessageDelete => 72,
+            AuditLogEvent::MessageBulkDelete => 73,
+            AuditLogEvent::MessagePin => 74,
+            AuditLogEvent::MessageUnpin => 75,
+            AuditLogEvent::IntegrationCreate => 80,
+            AuditLogEvent::IntegrationUpdate => 81,
+            AuditLogEvent::IntegrationDelete => 82,
+            AuditLogEvent::StageInstanceCreate => 83,
+            AuditLogEvent::StageInstanceUpdate => 84,
+            AuditLogEvent::StageInstanceDelete => 85,
+            AuditLogEvent::StickerCreate => 90,
+            AuditLogEvent::StickerUpdate => 91,
+            AuditLogEvent::StickerDelete => 92,
+            AuditLogEvent::GuildScheduledEventCreate => 100,
+            AuditLogEvent::GuildScheduledEventUpdate => 101,
+            AuditLogEvent::GuildScheduledEventDelete => 102,
+            AuditLogEvent::ThreadCreate => 110,
+            AuditLogEvent::ThreadUpdate => 111,
+            AuditLogEvent::ThreadDelete => 112,
+            AuditLogEvent::ApplicationCommandPermissionUpdate => 121,
+            AuditLogEvent::SoundboardSoundCreate => 130,
+            AuditLogEvent::SoundboardSoundUpdate => 131,
+            AuditLogEvent::SoundboardSoundDelete => 132,
+            AuditLogEvent::AutoModerationRuleCreate => 140,
+            AuditLogEvent::AutoModerationRuleUpdate => 141,
+            AuditLogEvent::AutoModerationRuleDelete => 142,
+            AuditLogEvent::AutoModerationBlockMessage => 143,
+            AuditLogEvent::AutoModerationFlagToChannel => 144,
+            AuditLogEvent::AutoModerationUserCommunicationDisabled => 145,
+            AuditLogEvent::CreatorMonetizationRequestCreated => 150,
+            AuditLogEvent::CreatorMonetizationTermsAccepted => 151,
+            AuditLogEvent::OnboardingPromptCreate => 163,
+            AuditLogEvent::OnboardingPromptUpdate => 164,
+            AuditLogEvent::OnboardingPromptDelete => 165,
+            AuditLogEvent::OnboardingCreate => 166,
+            AuditLogEvent::OnboardingUpdate => 167,
+            AuditLogEvent::HomeSettingsCreate => 190,
+            AuditLogEvent::HomeSettingsUpdate => 191,
+            AuditLogEvent::Unknown(v) => v,
+        }
+    }
+}
+
+/// A changed field. Values keep the shape of the changed object's field,
+/// so they're left as JSON; use `old_value_as`/`new_value_as` to read them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogChange {
+    pub key: AuditLogChangeKey,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<Value>,
+}
+
+impl AuditLogChange {
+    pub fn old_value_as<T: DeserializeOwned>(&self) -> Option<T> {
+        self.old_value.clone().and_then(|v| serde_json::from_value(v).ok())
+    }
+
+    pub fn new_value_as<T: DeserializeOwned>(&self) -> Option<T> {
+        self.new_value.clone().and_then(|v| serde_json::from_value(v).ok())
+    }
+}
+
+macro_rules! change_keys {
+    ($($(#[$meta:meta])* $variant:ident => $key:literal,)*) => {
+        /// Name of a changed field. Keys Discord adds later come through as `Other`.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum AuditLogChangeKey {
+            $($(#[$meta])* $variant,)*
+            Other(String),
+        }
+
+        impl AuditLogChangeKey {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(AuditLogChangeKey::$variant => $key,)*
+                    AuditLogChangeKey::Other(key) => key,
+                }
+            }
+        }
+
+        impl From<String> for AuditLogChangeKey {
+            fn from(key: String) -> Self {
+                match key.as_str() {
+                    $($key => AuditLogChangeKey::$variant,)*
+                    _ => AuditLogChangeKey::Other(key),
+                }
+            }
+        }
+    };
+}
+
+change_keys! {
+    /// Roles added to a member; the values are partial roles (`id`, `name`).
+    RoleAdd => "$add",
+    /// Roles removed from a member.
+    RoleRemove => "$remove",
+    Actions => "actions",
+    AfkChannelId => "afk_channel_id",
+    AfkTimeout => "afk_timeout",
+    Allow => "allow",
+    ApplicationId => "application_id",
+    AppliedTags => "applied_tags",
+    Archived => "archived",
+    Asset => "asset",
+    AutoArchiveDuration => "auto_archive_duration",
+    Available => "available",
+    AvailableTags => "available_tags",
+    AvatarHash => "avatar_hash",
+    BannerHash => "banner_hash",
+    Bitrate => "bitrate",
+    ChannelId => "channel_id",
+    Code => "code",
+    Color => "color",
+    CommunicationDisabledUntil => "communication_disabled_until",
+    Deaf => "deaf",
+    DefaultAutoArchiveDuration => "default_auto_archive_duration",
+    DefaultMessageNotifications => "default_message_notifications",
+    DefaultReactionEmoji => "default_reaction_emoji",
+    DefaultThreadRateLimitPerUser => "default_thread_rate_limit_per_user",
+    Deny => "deny",
+    Description => "description",
+    DiscoverySplashHash => "discovery_splash_hash",
+    EnableEmoticons => "enable_emoticons",
+    Enabled => "enabled",
+    EntityType => "entity_type",
+    EventType => "event_type",
+    ExemptChannels => "exempt_channels",
+    ExemptRoles => "exempt_roles",
+    ExpireBehavior => "expire_behavior",
+    ExpireGracePeriod => "expire_grace_period",
+    ExplicitContentFilter => "explicit_content_filter",
+    Flags => "flags",
+    FormatType => "format_type",
+    GuildId => "guild_id",
+    Hoist => "hoist",
+    IconHash => "icon_hash",
+    Id => "id",
+    ImageHash => "image_hash",
+    Invitable => "invitable",
+    InviterId => "inviter_id",
+    Location => "location",
+    Locked => "locked",
+    MaxAge => "max_age",
+    MaxUses => "max_uses",
+    Mentionable => "mentionable",
+    MfaLevel => "mfa_level",
+    Mute => "mute",
+    Name => "name",
+    Nick => "nick",
+    Nsfw => "nsfw",
+    OwnerId => "owner_id",
+    PermissionOverwrites => "permission_overwrites",
+    Permissions => "permissions",
+    Position => "position",
+    PreferredLocale => "preferred_locale",
+    PremiumProgressBarEnabled => "premium_progress_bar_enabled",
+    PrivacyLevel => "privacy_level",
+    PruneDeleteDays => "prune_delete_days",
+    PublicUpdatesChannelId => "public_updates_channel_id",
+    RateLimitPerUser => "rate_limit_per_user",
+    Region => "region",
+    RulesChannelId => "rules_channel_id",
+    SplashHash => "splash_hash",
+    Status => "status",
+    SystemChannelFlags => "system_channel_flags",
+    SystemChannelId => "system_channel_id",
+    Tags => "tags",
+    Temporary => "temporary",
+    Topic => "topic",
+    TriggerMetadata => "trigger_metadata",
+    TriggerType => "trigger_type",
+    Type => "type",
+    UnicodeEmoji => "unicode_emoji",
+    UserLimit => "user_limit",
+    Uses => "uses",
+    VanityUrlCode => "vanity_url_code",
+    VerificationLevel => "verification_level",
+    WidgetChannelId => "widget_channel_id",
+    WidgetEnabled => "widget_enabled",
+}
+
+impl From<AuditLogChangeKey> for String {
+    fn from(key: AuditLogChangeKey) -> Self {
+        match key {
+            AuditLogChangeKey::Other(key) => key,
+            key => key.as_str().to_string(),
+        }
+    }
+}
+
+/// Extra details for some action types. Numbers are sent as strings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditLogEntryInfo {
+    /// `ApplicationCommandPermissionUpdate`.
+    pub application_id: Option<Snowflake>,
+    /// Auto moderation actions.
+    pub auto_moderation_rule_name: Option<String>,
+    pub auto_moderation_rule_trigger_type: Option<String>,
+    /// Channel of message deletes, pins, member moves, stage instance and
+    /// auto moderation actions.
+    pub channel_id: Option<Snowflake>,
+    /// Number of deleted messages or moved/disconnected members.
+    pub count: Option<String>,
+    /// `MemberPrune`.
+    pub delete_member_days: Option<String>,
+    pub members_removed: Option<String>,
+    /// Overwritten role or member, for channel overwrite actions.
+    pub id: Option<Snowflake>,
+    pub message_id: Option<Snowflake>,
+    /// Set when the overwritten entity is a role.
+    pub role_name: Option<String>,
+    /// Overwrite type: `"0"` for roles, `"1"` for members.
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    /// `MemberKick` and `MemberRoleUpdate` caused by an integration.
+    pub integration_type: Option<String>,
+}
+
+/// Query for `GET /guilds/{guild.id}/audit-logs`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetGuildAuditLog {
+    /// Only entries made by this user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<AuditLogEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>, // 1-100, defaults to 50
+}
+
+impl GetGuildAuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn user_id(mut self, user_id: Snowflake) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    pub fn action_type(mut self, action_type: AuditLogEvent) -> Self {
+        self.action_type = Some(action_type);
+        self
+    }
+
+    pub fn before(mut self, id: Snowflake) -> Self {
+        self.before = Some(id);
+        self
+    }
+
+    pub fn after(mut self, id: Snowflake) -> Self {
+        self.after = Some(id);
+        self
+    }
+
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
@@ -0,0 +1,435 @@
+//! Auto moderation rules, their REST bodies and the
+//! `AUTO_MODERATION_ACTION_EXECUTION` event.
+
+use discord_rs_core::Snowflake;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoModerationRule {
+    pub id: Snowflake,
+    pub guild_id: Snowflake,
+    pub name: String,
+    pub creator_id: Snowflake,
+    pub event_type: AutoModerationEventType,
+    pub trigger_type: AutoModerationTriggerType,
+    #[serde(default)]
+    pub trigger_metadata: AutoModerationTriggerMetadata,
+    pub actions: Vec<AutoModerationAction>,
+    pub enabled: bool,
+    #[serde(default)]
+    pub exempt_roles: Vec<Snowflake>,
+    #[serde(default)]
+    pub exempt_channels: Vec<Snowflake>,
+}
+
+/// What a rule is checked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum AutoModerationEventType {
+    MessageSend = 1,
+    /// Member joins and profile edits.
+    MemberUpdate = 2,
+    Unknown(u8),
+}
+
+impl From<u8> for AutoModerationEventType {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => AutoModerationEventType::MessageSend,
+            2 => AutoModerationEventType::MemberUpdate,
+            _ => AutoModerationEventType::Unknown(v),
+        }
+    }
+}
+
+impl From<AutoModerationEventType> for u8 {
+    fn from(v: AutoModerationEventType) -> Self {
+        match v {
+            AutoModerationEventType::MessageSend => 1,
+            AutoModerationEventType::MemberUpdate => 2,
+            AutoModerationEventType::Unknown(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum AutoModerationTriggerType {
+    /// Words and regexes from `keyword_filter` and `regex_patterns`.
+    Keyword = 1,
+    Spam = 3,
+    /// Discord's predefined word lists, see `presets`.
+    KeywordPreset = 4,
+    /// Too many unique role and user mentions.
+    MentionSpam = 5,
+    /// Words in a member's profile.
+    MemberProfile = 6,
+    Unknown(u8),
+}
+
+impl From<u8> for AutoModerationTriggerType {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => AutoModerationTriggerType::Keyword,
+            3 => AutoModerationTriggerType::Spam,
+            4 => AutoModerationTriggerType::KeywordPreset,
+            5 => AutoModerationTriggerType::MentionSpam,
+            6 => AutoModerationTriggerType::MemberProfile,
+            _ => AutoModerationTriggerType::Unknown(v),
+        }
+    }
+}
+
+impl From<AutoModerationTriggerType> for u8 {
+    fn from(v: AutoModerationTriggerType) -> Self {
+        match v {
+            AutoModerationTriggerType::Keyword => 1,
+            AutoModerationTriggerType::Spam => 3,
+            AutoModerationTriggerType::KeywordPreset => 4,
+            AutoModerationTriggerType::MentionSpam => 5,
+            AutoModerationTriggerType::MemberProfile => 6,
+            AutoModerationTriggerType::Unknown(v) => v,
+        }
+    }
+}
+
+/// Trigger settings. Which fields apply depends on the trigger type; unset
+/// ones are left out of requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AutoModerationTriggerMetadata {
+    /// `Keyword` and `MemberProfile`. Supports `*` wildcards.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keyword_filter: Vec<String>,
+    /// `Keyword` and `MemberProfile`. Rust-flavored regexes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regex_patterns: Vec<String>,
+    /// `KeywordPreset`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub presets: Vec<KeywordPresetType>,
+    /// Exceptions to `keyword_filter` or `presets`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow_list: Vec<String>,
+    /// `MentionSpam`, up to 50.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mention_total_limit: Option<u8>,
+    /// `MentionSpam`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mention_raid_protection_enabled: Option<bool>,
+}
+
+impl AutoModerationTriggerMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn keyword(mut self, keyword: impl Into<String>) -> Self {
+        self.keyword_filter.push(keyword.into());
+        self
+    }
+
+    pub fn regex(mut self, pattern: impl Into<String>) -> Self {
+        self.regex_patterns.push(pattern.into());
+        self
+    }
+
+    pub fn preset(mut self, preset: KeywordPresetType) -> Self {
+        self.presets.push(preset);
+        self
+    }
+
+    pub fn allow(mut self, keyword: impl Into<String>) -> Self {
+        self.allow_list.push(keyword.into());
+        self
+    }
+
+    pub fn mention_total_limit(mut self, limit: u8) -> Self {
+        self.mention_total_limit = Some(limit);
+        self
+    }
+
+    pub fn mention_raid_protection(mut self, enabled: bool) -> Self {
+        self.mention_raid_protection_enabled = Some(enabled);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum KeywordPresetType {
+    Profanity = 1,
+    SexualContent = 2,
+    Slurs = 3,
+    Unknown(u8),
+}
+
+impl From<u8> for KeywordPresetType {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => KeywordPresetType::Profanity,
+            2 => KeywordPresetType::SexualContent,
+            3 => KeywordPresetType::Slurs,
+            _ => KeywordPresetType::Unknown(v),
+        }
+    }
+}
+
+impl From<KeywordPresetType> for u8 {
+    fn from(v: KeywordPresetType) -> Self {
+        match v {
+            KeywordPresetType::Profanity => 1,
+            KeywordPresetType::SexualContent => 2,
+            KeywordPresetType::Slurs => 3,
+            KeywordPresetType::Unknown(v) => v,
+        }
+    }
+}
+
+/// What happens when a rule triggers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutoModerationAction {
+    #[serde(rename = "type")]
+    pub kind: AutoModerationActionType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<AutoModerationActionMetadata>,
+}
+
+impl AutoModerationAction {
+    /// Blocks the message, optionally telling the author why (up to 150 characters).
+    pub fn block_message(custom_message: Option<String>) -> Self {
+        Self {
+            kind: AutoModerationActionType::BlockMessage,
+            metadata: custom_message.map(|message| AutoModerationActionMetadata {
+                custom_message: Some(message),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Posts an alert to `channel_id`.
+    pub fn send_alert_message(channel_id: Snowflake) -> Self {
+        Self {
+            kind: AutoModerationActionType::SendAlertMessage,
+            metadata: Some(AutoModerationActionMetadata {
+                channel_id: Some(channel_id),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Times the member out, for up to four weeks. Only for `Keyword` and
+    /// `MentionSpam` rules.
+    pub fn timeout(duration_seconds: u32) -> Self {
+        Self {
+            kind: AutoModerationActionType::Timeout,
+            metadata: Some(AutoModerationActionMetadata {
+                duration_seconds: Some(duration_seconds),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Stops the member from interacting in the guild. Only for
+    /// `MemberUpdate` rules.
+    pub fn block_member_interaction() -> Self {
+        Self {
+            kind: AutoModerationActionType::BlockMemberInteraction,
+            metadata: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum AutoModerationActionType {
+    BlockMessage = 1,
+    SendAlertMessage = 2,
+    Timeout = 3,
+    BlockMemberInteraction = 4,
+    Unknown(u8),
+}
+
+impl From<u8> for AutoModerationActionType {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => AutoModerationActionType::BlockMessage,
+            2 => AutoModerationActionType::SendAlertMessage,
+            3 => AutoModerationActionType::Timeout,
+            4 => AutoModerationActionType::BlockMemberInteraction,
+            _ => AutoModerationActionType::Unknown(v),
+        }
+    }
+}
+
+impl From<AutoModerationActionType> for u8 {
+    fn from(v: AutoModerationActionType) -> Self {
+        match v {
+            AutoModerationActionType::BlockMessage => 1,
+            AutoModerationActionType::SendAlertMessage => 2,
+            AutoModerationActionType::Timeout => 3,
+            AutoModerationActionType::BlockMemberInteraction => 4,
+            AutoModerationActionType::Unknown(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoModerationActionMetadata {
+    /// `SendAlertMessage`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+    /// `Timeout`, up to 2419200 (four weeks).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u32>,
+    /// `BlockMessage`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+/// Body for `POST /guilds/{guild.id}/auto-moderation/rules`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateAutoModerationRule {
+    pub name: String,
+    pub event_type: AutoModerationEventType,
+    pub trigger_type: AutoModerationTriggerType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_metadata: Option<AutoModerationTriggerMetadata>,
+    pub actions: Vec<AutoModerationAction>,
+    /// Rules are disabled unless this is `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exempt_roles: Vec<Snowflake>, // up to 20
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exempt_channels: Vec<Snowflake>, // up to 50
+}
+
+impl CreateAutoModerationRule {
+    pub fn new(
+        name: impl Into<String>,
+        event_type: AutoModerationEventType,
+        trigger_type: AutoModerationTriggerType,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            event_type,
+            trigger_type,
+            trigger_metadata: None,
+            actions: Vec::new(),
+            enabled: None,
+            exempt_roles: Vec::new(),
+            exempt_channels: Vec::new(),
+        }
+    }
+
+    pub fn trigger_metadata(mut self, metadata: AutoModerationTriggerMetadata) -> Self {
+        self.trigger_metadata = Some(metadata);
+        self
+    }
+
+    pub fn action(mut self, action: AutoModerationAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    pub fn exempt_role(mut self, role_id: Snowflake) -> Self {
+        self.exempt_roles.push(role_id);
+        self
+    }
+
+    pub fn exempt_channel(mut self, channel_id: Snowflake) -> Self {
+        self.exempt_channels.push(channel_id);
+        self
+    }
+}
+
+/// Body for `PATCH /guilds/{guild.id}/auto-moderation/rules/{rule.id}`.
+/// The trigger type can't be changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyAutoModerationRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<AutoModerationEventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_metadata: Option<AutoModerationTriggerMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<AutoModerationAction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_roles: Option<Vec<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_channels: Option<Vec<Snowflake>>,
+}
+
+impl ModifyAutoModerationRule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn event_type(mut self, event_type: AutoModerationEventType) -> Self {
+        self.event_type = Some(event_type);
+        self
+    }
+
+    pub fn trigger_metadata(mut self, metadata: AutoModerationTriggerMetadata) -> Self {
+        self.trigger_metadata = Some(metadata);
+        self
+    }
+
+    pub fn actions(mut self, actions: Vec<AutoModerationAction>) -> Self {
+        self.actions = Some(actions);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    pub fn exempt_roles(mut self, role_ids: Vec<Snowflake>) -> Self {
+        self.exempt_roles = Some(role_ids);
+        self
+    }
+
+    pub fn exempt_channels(mut self, channel_ids: Vec<Snowflake>) -> Self {
+        self.exempt_channels = Some(channel_ids);
+        self
+    }
+}
+
+/// Sent for each action a rule takes. Requires the
+/// `AUTO_MODERATION_EXECUTION` intent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoModerationActionExecution {
+    pub guild_id: Snowflake,
+    pub action: AutoModerationAction,
+    pub rule_id: Snowflake,
+    pub rule_trigger_type: AutoModerationTriggerType,
+    pub user_id: Snowflake,
+    pub channel_id: Option<Snowflake>,
+    /// Not set when the message was blocked.
+    pub message_id: Option<Snowflake>,
+    /// The alert posted by a `SendAlertMessage` action.
+    pub alert_system_message_id: Option<Snowflake>,
+    /// Empty without the `MESSAGE_CONTENT` intent.
+    #[serde(default)]
+    pub content: String,
+    pub matched_keyword: Option<String>,
+    /// Empty without the `MESSAGE_CONTENT` intent.
+    pub matched_content: Option<String>,
+}
@@ -0,0 +1,231 @@
+//! URLs of images served from Discord's CDN.
+//!
+//! Models only carry image hashes; the helpers here (and the `*_url`
+//! methods on `User`, `Guild`, `Emoji`, `Sticker`, ...) turn them into
+//! links, following the CDN's rules for animated hashes and allowed formats:
+//!
+//! ```
+//! use discord_rs_model::cdn::{self, ImageSize};
+//! use discord_rs_model::Snowflake;
+//!
+//! let url = cdn::user_avatar(Snowflake(80351110224678912), "a_8342729096ea3675442027381ff50dfe")
+//!     .size(ImageSize::new(256).unwrap())
+//!     .url();
+//! assert_eq!(
+//!     url,
+//!     "https://cdn.discordapp.com/avatars/80351110224678912/a_8342729096ea3675442027381ff50dfe.gif?size=256"
+//! );
+//! ```
+
+use crate::sticker::StickerFormatType;
+use discord_rs_core::{DiscordError, Result, Snowflake};
+use std::fmt;
+
+pub const CDN_BASE: &str = "https://cdn.discordapp.com";
+/// GIF stickers are only served from here.
+pub const MEDIA_BASE: &str = "https://media.discordapp.net";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    WebP,
+    Gif,
+    /// Lottie stickers, served as JSON.
+    Lottie,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::WebP => "webp",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Lottie => "json",
+        }
+    }
+}
+
+const STATIC_FORMATS: &[ImageFormat] = &[ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP];
+const ANIMATED_FORMATS: &[ImageFormat] = &[ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP, ImageFormat::Gif];
+
+/// Requested image size: a power of two from 16 to 4096.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageSize(u16);
+
+impl ImageSize {
+    pub const MIN: ImageSize = ImageSize(16);
+    pub const MAX: ImageSize = ImageSize(4096);
+
+    pub fn new(size: u16) -> Result<Self> {
+        if size.is_power_of_two() && (Self::MIN.0..=Self::MAX.0).contains(&size) {
+            Ok(ImageSize(size))
+        } else {
+            Err(DiscordError::Validation(format!(
+                "image size must be a power of two between 16 and 4096, got {}",
+                size
+            )))
+        }
+    }
+
+    pub fn get(self) -> u16 {
+        self.0
+    }
+}
+
+/// A CDN image. Defaults to GIF for animated hashes and PNG otherwise;
+/// renders via `url()` or `Display`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageUrl {
+    base: &'static str,
+    /// Path without extension, e.g. `/avatars/{user_id}/{hash}`.
+    path: String,
+    formats: &'static [ImageFormat],
+    format: ImageFormat,
+    size: Option<ImageSize>,
+}
+
+impl ImageUrl {
+    fn new(path: String, formats: &'static [ImageFormat], format: ImageFormat) -> Self {
+        Self {
+            base: CDN_BASE,
+            path,
+            formats,
+            format,
+            size: None,
+        }
+    }
+
+    fn hashed(path: String, hash: &str) -> Self {
+        if is_animated_hash(hash) {
+            Self::new(path, ANIMATED_FORMATS, ImageFormat::Gif)
+        } else {
+            Self::new(path, STATIC_FORMATS, ImageFormat::Png)
+        }
+    }
+
+    /// Fails for formats this image isn't served in, e.g. GIF for a static
+    /// avatar or anything but PNG for a default avatar.
+    pub fn format(mut self, format: ImageFormat) -> Result<Self> {
+        if !self.formats.contains(&format) {
+            return Err(DiscordError::Validation(format!(
+                "{} is not available as {}",
+                self.path,
+                format.extension()
+            )));
+        }
+        self.format = format;
+        Ok(self)
+    }
+
+    /// PNG instead of GIF for animated images; no-op otherwise.
+    pub fn static_image(mut self) -> Self {
+        if self.format == ImageFormat::Gif && self.formats.contains(&ImageFormat::Png) {
+            self.format = ImageFormat::Png;
+        }
+        self
+    }
+
+    pub fn size(mut self, size: ImageSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn current_format(&self) -> ImageFormat {
+        self.format
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.formats.contains(&ImageFormat::Gif)
+    }
+
+    pub fn url(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for ImageUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}.{}", self.base, self.path, self.format.extension())?;
+        if let Some(size) = self.size {
+            write!(f, "?size={}", size.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Hashes of animated images start with `a_`.
+pub fn is_animated_hash(hash: &str) -> bool {
+    hash.starts_with("a_")
+}
+
+pub fn user_avatar(user_id: Snowflake, hash: &str) -> ImageUrl {
+    ImageUrl::hashed(format!("/avatars/{}/{}", user_id, hash), hash)
+}
+
+/// Avatar shown for users without one. Users on the new username system
+/// (discriminator `0`) are keyed by id, legacy users by discriminator.
+pub fn default_user_avatar(user_id: Snowflake, discriminator: &str) -> ImageUrl {
+    let index = match discriminator.parse::<u16>() {
+        Ok(discriminator) if discriminator != 0 => u64::from(discriminator % 5),
+        _ => (user_id.0 >> 22) % 6,
+    };
+    ImageUrl::new(format!("/embed/avatars/{}", index), &[ImageFormat::Png], ImageFormat::Png)
+}
+
+pub fn user_banner(user_id: Snowflake, hash: &str) -> ImageUrl {
+    ImageUrl::hashed(format!("/banners/{}/{}", user_id, hash), hash)
+}
+
+pub fn guild_member_avatar(guild_id: Snowflake, user_id: Snowflake, hash: &str) -> ImageUrl {
+    ImageUrl::hashed(format!("/guilds/{}/users/{}/avatars/{}", guild_id, user_id, hash), hash)
+}
+
+pub fn guild_icon(guild_id: Snowflake, hash: &str) -> ImageUrl {
+    ImageUrl::hashed(format!("/icons/{}/{}", guild_id, hash), hash)
+}
+
+pub fn guild_splash(guild_id: Snowflake, hash: &str) -> ImageUrl {
+    ImageUrl::new(format!("/splashes/{}/{}", guild_id, hash), STATIC_FORMATS, ImageFormat::Png)
+}
+
+pub fn guild_discovery_splash(guild_id: Snowflake, hash: &str) -> ImageUrl {
+    ImageUrl::new(
+        format!("/discovery-splashes/{}/{}", guild_id, hash),
+        STATIC_FORMATS,
+        ImageFormat::Png,
+    )
+}
+
+pub fn guild_banner(guild_id: Snowflake, hash: &str) -> ImageUrl {
+    ImageUrl::hashed(format!("/banners/{}/{}", guild_id, hash), hash)
+}
+
+pub fn role_icon(role_id: Snowflake, hash: &str) -> ImageUrl {
+    ImageUrl::new(format!("/role-icons/{}/{}", role_id, hash), STATIC_FORMATS, ImageFormat::Png)
+}
+
+/// Custom emojis have no hash; whether they're animated comes from the emoji.
+pub fn emoji(emoji_id: Snowflake, animated: bool) -> ImageUrl {
+    let path = format!("/emojis/{}", emoji_id);
+    if animated {
+        ImageUrl::new(path, ANIMATED_FORMATS, ImageFormat::Gif)
+    } else {
+        ImageUrl::new(path, STATIC_FORMATS, ImageFormat::Png)
+    }
+}
+
+/// Stickers come in exactly one format, decided by their format type.
+/// APNG stickers are served with a `.png` extension.
+pub fn sticker(sticker_id: Snowflake, format_type: StickerFormatType) -> ImageUrl {
+    let path = format!("/stickers/{}", sticker_id);
+    match format_type {
+        StickerFormatType::Lottie => ImageUrl::new(path, &[ImageFormat::Lottie], ImageFormat::Lottie),
+        StickerFormatType::Gif => ImageUrl {
+            base: MEDIA_BASE,
+            ..ImageUrl::new(path, &[ImageFormat::Gif], ImageFormat::Gif)
+        },
+        _ => ImageUrl::new(path, &[ImageFormat::Png], ImageFormat::Png),
+    }
+}
@@ -32,12 +32,20 @@ pub struct Channel {
     pub message_count: Option<i32>,
     pub member_count: Option<i32>,
     pub thread_metadata: Option<ThreadMetadata>,
-    pub member: Option<Member>, // Thread member
+    pub member: Option<ThreadMember>, // Present on threads the current user has joined
     pub default_auto_archive_duration: Option<i32>,
     pub permissions: Option<String>,
     #[serde(default)]
     pub flags: i32,
     pub total_message_sent: Option<i32>,
+    #[serde(default)]
+    pub available_tags: Vec<ForumTag>, // Forum and media channels
+    #[serde(default)]
+    pub applied_tags: Vec<Snowflake>, // Threads in forum and media channels
+    pub default_reaction_emoji: Option<DefaultReaction>,
+    pub default_thread_rate_limit_per_user: Option<i32>,
+    pub default_sort_order: Option<i32>,
+    pub default_forum_layout: Option<i32>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -118,3 +126,160 @@ pub struct ThreadMetadata {
     pub invitable: bool,
     pub create_timestamp: Option<String>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadMember {
+    pub id: Option<Snowflake>, // Omitted in GUILD_CREATE
+    pub user_id: Option<Snowflake>, // Omitted in GUILD_CREATE
+    pub join_timestamp: String,
+    #[serde(default)]
+    pub flags: i32,
+    pub member: Option<Member>, // Only with `with_member=true`
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForumTag {
+    pub id: Snowflake,
+    pub name: String,
+    #[serde(default)]
+    pub moderated: bool,
+    pub emoji_id: Option<Snowflake>,
+    pub emoji_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DefaultReaction {
+    pub emoji_id: Option<Snowflake>,
+    pub emoji_name: Option<String>,
+}
+
+/// Response of the active and archived thread listing endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadList {
+    pub threads: Vec<Channel>,
+    #[serde(default)]
+    pub members: Vec<ThreadMember>, // Thread members for the current user
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// Body for `POST /channels/{channel.id}/messages/{message.id}/threads`.
+#[derive(Debug, Clone, Serialize)]
+pub struct StartThreadFromMessage {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>, // 60, 1440, 4320 or 10080 minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+}
+
+impl StartThreadFromMessage {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            auto_archive_duration: None,
+            rate_limit_per_user: None,
+        }
+    }
+}
+
+/// Body for `POST /channels/{channel.id}/threads` in text and announcement channels.
+#[derive(Debug, Clone, Serialize)]
+pub struct StartThreadWithoutMessage {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>,
+    #[serde(rename = "type")]
+    pub kind: ChannelType, // PublicThread, PrivateThread or AnnouncementThread
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>, // Private threads only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+}
+
+impl StartThreadWithoutMessage {
+    pub fn new(name: impl Into<String>, kind: ChannelType) -> Self {
+        Self {
+            name: name.into(),
+            auto_archive_duration: None,
+            kind,
+            invitable: None,
+            rate_limit_per_user: None,
+        }
+    }
+}
+
+/// Body for `POST /channels/{channel.id}/threads` in forum and media channels.
+#[derive(Debug, Clone, Serialize)]
+pub struct StartForumThread {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+    pub message: serde_json::Value, // Starter message, usually a built MessageBuilder
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub applied_tags: Vec<Snowflake>,
+}
+
+impl StartForumThread {
+    pub fn new(name: impl Into<String>, message: impl Into<serde_json::Value>) -> Self {
+        Self {
+            name: name.into(),
+            auto_archive_duration: None,
+            rate_limit_per_user: None,
+            message: message.into(),
+            applied_tags: Vec::new(),
+        }
+    }
+
+    pub fn tag(mut self, tag_id: Snowflake) -> Self {
+        self.applied_tags.push(tag_id);
+        self
+    }
+}
+
+/// Query for `GET /channels/{channel.id}/thread-members`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetThreadMembers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_member: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>, // 1-100
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchivedThreadKind {
+    Public,
+    Private,
+    JoinedPrivate,
+}
+
+/// Query for the archived thread listing endpoints.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListArchivedThreads {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>, // ISO8601 timestamp, or a thread id for joined private threads
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>,
+}
+
+impl ListArchivedThreads {
+    /// Builds the query for the page following `page`, or `None` when there are no more threads.
+    pub fn next_page(&self, kind: ArchivedThreadKind, page: &ThreadList) -> Option<Self> {
+        if !page.has_more {
+            return None;
+        }
+        let last = page.threads.last()?;
+        let before = match kind {
+            ArchivedThreadKind::JoinedPrivate => last.id.to_string(),
+            _ => last.thread_metadata.as_ref()?.archive_timestamp.clone(),
+        };
+        Some(Self {
+            before: Some(before),
+            limit: self.limit,
+        })
+    }
+}
@@ -88,6 +88,7 @@ impl From<ButtonStyle> for u8 {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum Component {
     #[serde(rename = "1")]
     ActionRow(ActionRow),
@@ -1,5 +1,7 @@
 use discord_rs_core::Snowflake;
 use serde::{Deserialize, Serialize};
+use crate::cdn::{self, ImageUrl};
+use crate::image::ImageData;
 use crate::user::User;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,3 +20,85 @@ pub struct Emoji {
     #[serde(default)]
     pub available: bool,
 }
+
+impl Emoji {
+    /// `None` for unicode emojis.
+    pub fn url(&self) -> Option<ImageUrl> {
+        self.id.map(|id| cdn::emoji(id, self.animated))
+    }
+}
+
+/// Body for `POST /guilds/{guild.id}/emojis`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateGuildEmoji {
+    pub name: String,
+    /// Data URI of a 128x128 image, at most 256 KiB.
+    pub image: String,
+    /// Roles allowed to use the emoji; everyone if empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<Snowflake>,
+}
+
+impl CreateGuildEmoji {
+    pub fn new(name: impl Into<String>, image: &ImageData) -> Self {
+        Self {
+            name: name.into(),
+            image: image.to_data_uri(),
+            roles: Vec::new(),
+        }
+    }
+
+    pub fn roles(mut self, roles: Vec<Snowflake>) -> Self {
+        self.roles = roles;
+        self
+    }
+}
+
+/// Body for `PATCH /guilds/{guild.id}/emojis/{emoji.id}`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyGuildEmoji {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// `Some(None)` makes the emoji usable by everyone again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Option<Vec<Snowflake>>>,
+}
+
+impl ModifyGuildEmoji {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn roles(mut self, roles: Option<Vec<Snowflake>>) -> Self {
+        self.roles = Some(roles);
+        self
+    }
+}
+
+/// Body for `POST /applications/{application.id}/emojis`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateApplicationEmoji {
+    pub name: String,
+    /// Data URI of a 128x128 image, at most 256 KiB.
+    pub image: String,
+}
+
+impl CreateApplicationEmoji {
+    pub fn new(name: impl Into<String>, image: &ImageData) -> Self {
+        Self {
+            name: name.into(),
+            image: image.to_data_uri(),
+        }
+    }
+}
+
+/// Response of `GET /applications/{application.id}/emojis`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationEmojis {
+    pub items: Vec<Emoji>,
+}
@@ -2,10 +2,13 @@ use serde::{Deserialize, Serialize};
 use crate::gateway::Ready;
 use crate::message::Message;
 use crate::guild::Guild;
+use crate::audit_log::AuditLogEntry;
+use crate::auto_moderation::{AutoModerationActionExecution, AutoModerationRule};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "t", content = "d")] // standard discord dispatch format mapping
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::large_enum_variant)]
 pub enum Event {
     Ready(Ready),
     Resumed(serde_json::Value),
@@ -16,6 +19,13 @@ pub enum Event {
     GuildUpdate(Guild),
     GuildDelete(serde_json::Value),
     InteractionCreate(crate::interaction::Interaction),
+    /// Requires `VIEW_AUDIT_LOG` and the `GUILD_MODERATION` intent.
+    GuildAuditLogEntryCreate(Box<AuditLogEntry>),
+    /// Rule events require the `AUTO_MODERATION_CONFIGURATION` intent.
+    AutoModerationRuleCreate(Box<AutoModerationRule>),
+    AutoModerationRuleUpdate(Box<AutoModerationRule>),
+    AutoModerationRuleDelete(Box<AutoModerationRule>),
+    AutoModerationActionExecution(Box<AutoModerationActionExecution>),
     // We will add more events as we implement more models/features
     #[serde(other)]
     Unknown,
@@ -1,3 +1,4 @@
+use discord_rs_core::Snowflake;
 use serde::{Deserialize, Serialize};
 use crate::presence::PresenceUpdate;
 
@@ -107,4 +108,12 @@ pub struct Ready {
     pub guilds: Vec<UnavailableGuild>,
     pub session_id: String,
     pub resume_gateway_url: String,
+    pub application: Option<ReadyApplication>,
+}
+
+/// The application the bot belongs to, as sent in READY.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadyApplication {
+    pub id: Snowflake,
+    pub flags: Option<u64>,
 }
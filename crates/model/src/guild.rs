@@ -1,8 +1,12 @@
 use discord_rs_core::Snowflake;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use crate::role::Role;
 use crate::emoji::Emoji;
 use crate::sticker::Sticker;
+use crate::channel::{ChannelType, PermissionOverwrite};
+use crate::user::User;
+use crate::cdn::{self, ImageUrl};
+use crate::image::ImageData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Guild {
@@ -53,6 +57,26 @@ pub struct Guild {
     pub premium_progress_bar_enabled: bool,
 }
 
+impl Guild {
+    pub fn icon_url(&self) -> Option<ImageUrl> {
+        self.icon.as_deref().map(|hash| cdn::guild_icon(self.id, hash))
+    }
+
+    pub fn splash_url(&self) -> Option<ImageUrl> {
+        self.splash.as_deref().map(|hash| cdn::guild_splash(self.id, hash))
+    }
+
+    pub fn discovery_splash_url(&self) -> Option<ImageUrl> {
+        self.discovery_splash
+            .as_deref()
+            .map(|hash| cdn::guild_discovery_splash(self.id, hash))
+    }
+
+    pub fn banner_url(&self) -> Option<ImageUrl> {
+        self.banner.as_deref().map(|hash| cdn::guild_banner(self.id, hash))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnavailableGuild {
     pub id: Snowflake,
@@ -68,3 +92,217 @@ pub struct PartialGuild {
     pub owner: Option<bool>,
     pub permissions: Option<String>,
 }
+
+impl PartialGuild {
+    pub fn icon_url(&self) -> Option<ImageUrl> {
+        self.icon.as_deref().map(|hash| cdn::guild_icon(self.id, hash))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildPreview {
+    pub id: Snowflake,
+    pub name: String,
+    pub icon: Option<String>,
+    pub splash: Option<String>,
+    pub discovery_splash: Option<String>,
+    #[serde(default)]
+    pub emojis: Vec<Emoji>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    pub approximate_member_count: i32,
+    pub approximate_presence_count: i32,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildWidgetSettings {
+    pub enabled: bool,
+    pub channel_id: Option<Snowflake>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildWidget {
+    pub id: Snowflake,
+    pub name: String,
+    pub instant_invite: Option<String>,
+    #[serde(default)]
+    pub channels: Vec<serde_json::Value>, // Partial channels
+    #[serde(default)]
+    pub members: Vec<serde_json::Value>, // Anonymized partial users
+    pub presence_count: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VanityUrl {
+    pub code: Option<String>,
+    #[serde(default)]
+    pub uses: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PruneCount {
+    pub pruned: Option<i32>, // null when compute_prune_count is false
+}
+
+/// Body for `PATCH /guilds/{guild.id}`. Unset fields are left untouched;
+/// `Some(None)` clears nullable ones such as `icon` or `afk_channel_id`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyGuild {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_level: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_message_notifications: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explicit_content_filter: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub afk_channel_id: Option<Option<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub afk_timeout: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<Option<ImageData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub splash: Option<Option<ImageData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discovery_splash: Option<Option<ImageData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner: Option<Option<ImageData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_channel_id: Option<Option<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_channel_flags: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules_channel_id: Option<Option<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_updates_channel_id: Option<Option<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_progress_bar_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_alerts_channel_id: Option<Option<Snowflake>>,
+}
+
+/// Body for `POST /guilds/{guild.id}/channels`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateGuildChannel {
+    pub name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ChannelType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitrate: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rtc_region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_quality_mode: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_auto_archive_duration: Option<i32>,
+}
+
+impl CreateGuildChannel {
+    pub fn new(name: impl Into<String>, kind: ChannelType) -> Self {
+        Self {
+            name: name.into(),
+            kind: Some(kind),
+            topic: None,
+            bitrate: None,
+            user_limit: None,
+            rate_limit_per_user: None,
+            position: None,
+            permission_overwrites: Vec::new(),
+            parent_id: None,
+            nsfw: None,
+            rtc_region: None,
+            video_quality_mode: None,
+            default_auto_archive_duration: None,
+        }
+    }
+}
+
+/// One entry of the body for `PATCH /guilds/{guild.id}/channels`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModifyGuildChannelPosition {
+    pub id: Snowflake,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_permissions: Option<bool>,
+    /// `Some(None)` moves the channel out of its category.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Option<Snowflake>>,
+}
+
+/// Query for `GET /guilds/{guild.id}/prune`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetGuildPruneCount {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<u8>, // 1-30, defaults to 7
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "comma_separated")]
+    pub include_roles: Vec<Snowflake>,
+}
+
+/// Body for `POST /guilds/{guild.id}/prune`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BeginGuildPrune {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_prune_count: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include_roles: Vec<Snowflake>,
+}
+
+// Query strings take role lists as `id,id,id`.
+fn comma_separated<S: Serializer>(ids: &[Snowflake], serializer: S) -> Result<S::Ok, S::Error> {
+    let joined = ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",");
+    serializer.serialize_str(&joined)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ban {
+    pub reason: Option<String>,
+    pub user: User,
+}
+
+/// Query for `GET /guilds/{guild.id}/bans`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetGuildBans {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u16>, // 1-1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Snowflake>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkBanResponse {
+    #[serde(default)]
+    pub banned_users: Vec<Snowflake>,
+    #[serde(default)]
+    pub failed_users: Vec<Snowflake>,
+}
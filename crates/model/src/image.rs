@@ -0,0 +1,69 @@
+//! Images sent inline in JSON bodies as data URIs, e.g. emoji images,
+//! webhook avatars and role icons.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use discord_rs_core::{DiscordError, Result};
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageData {
+    content_type: String,
+    data: Vec<u8>,
+}
+
+impl ImageData {
+    /// Detects PNG, JPEG, GIF and WebP from the file signature.
+    pub fn new(data: impl Into<Vec<u8>>) -> Result<Self> {
+        let data = data.into();
+        let content_type = sniff_content_type(&data).ok_or_else(|| {
+            DiscordError::Validation("image must be PNG, JPEG, GIF or WebP".to_string())
+        })?;
+        Ok(Self {
+            content_type: content_type.to_string(),
+            data,
+        })
+    }
+
+    /// Skips detection, e.g. for formats not recognized by `new`.
+    pub fn with_content_type(content_type: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            content_type: content_type.into(),
+            data: data.into(),
+        }
+    }
+
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// `data:{content type};base64,{data}`, as expected by image fields.
+    pub fn to_data_uri(&self) -> String {
+        format!("data:{};base64,{}", self.content_type, STANDARD.encode(&self.data))
+    }
+}
+
+/// Serializes as the data URI, so typed image fields can hold `ImageData` directly.
+impl Serialize for ImageData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_data_uri())
+    }
+}
+
+fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(b"\xff\xd8\xff") {
+        Some("image/jpeg")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
@@ -64,6 +64,7 @@ impl From<InteractionType> for u8 {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum InteractionData {
     ApplicationCommand(ApplicationCommandData),
     MessageComponent(MessageComponentData),
@@ -197,7 +198,7 @@ impl From<InteractionResponseType> for u8 {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InteractionResponseData {
     pub tts: Option<bool>,
     pub content: Option<String>,
@@ -208,4 +209,429 @@ pub struct InteractionResponseData {
     pub choices: Option<Vec<serde_json::Value>>,
     pub custom_id: Option<String>,
     pub title: Option<String>,
+    /// Entries for the files uploaded with the response, see `InteractionResponseBuilder::add_file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<crate::attachment::PartialAttachment>>,
+}
+
+pub type Localizations = std::collections::HashMap<String, String>;
+
+/// A registered command, as returned by the application command endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationCommand {
+    pub id: Snowflake,
+    #[serde(rename = "type", default = "ApplicationCommandType::chat_input")]
+    pub kind: ApplicationCommandType,
+    pub application_id: Snowflake,
+    pub guild_id: Option<Snowflake>,
+    pub name: String,
+    pub name_localizations: Option<Localizations>,
+    #[serde(default)]
+    pub description: String,
+    pub description_localizations: Option<Localizations>,
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    pub default_member_permissions: Option<String>,
+    pub dm_permission: Option<bool>,
+    #[serde(default)]
+    pub nsfw: bool,
+    /// Autoincrementing version, changed on every update.
+    pub version: Snowflake,
+}
+
+impl ApplicationCommandType {
+    fn chat_input() -> Self {
+        ApplicationCommandType::ChatInput
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum ApplicationCommandOptionType {
+    SubCommand = 1,
+    SubCommandGroup = 2,
+    String = 3,
+    Integer = 4,
+    Boolean = 5,
+    User = 6,
+    Channel = 7,
+    Role = 8,
+    Mentionable = 9,
+    Number = 10,
+    Attachment = 11,
+    Unknown(u8),
+}
+
+impl From<u8> for ApplicationCommandOptionType {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => ApplicationCommandOptionType::SubCommand,
+            2 => ApplicationCommandOptionType::SubCommandGroup,
+            3 => ApplicationCommandOptionType::String,
+            4 => ApplicationCommandOptionType::Integer,
+            5 => ApplicationCommandOptionType::Boolean,
+            6 => ApplicationCommandOptionType::User,
+            7 => ApplicationCommandOptionType::Channel,
+            8 => ApplicationCommandOptionType::Role,
+            9 => ApplicationCommandOptionType::Mentionable,
+            10 => ApplicationCommandOptionType::Number,
+            11 => ApplicationCommandOptionType::Attachment,
+            _ => ApplicationCommandOptionType::Unknown(v),
+        }
+    }
+}
+
+impl From<ApplicationCommandOptionType> for u8 {
+    fn from(v: ApplicationCommandOptionType) -> u8 {
+        match v {
+            ApplicationCommandOptionType::SubCommand => 1,
+            ApplicationCommandOptionType::SubCommandGroup => 2,
+            ApplicationCommandOptionType::String => 3,
+            ApplicationCommandOptionType::Integer => 4,
+            ApplicationCommandOptionType::Boolean => 5,
+            ApplicationCommandOptionType::User => 6,
+            ApplicationCommandOptionType::Channel => 7,
+            ApplicationCommandOptionType::Role => 8,
+            ApplicationCommandOptionType::Mentionable => 9,
+            ApplicationCommandOptionType::Number => 10,
+            ApplicationCommandOptionType::Attachment => 11,
+            ApplicationCommandOptionType::Unknown(v) => v,
+        }
+    }
+}
+
+/// A command parameter or subcommand.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationCommandOption {
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<Localizations>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+    /// Nested options of a subcommand or subcommand group.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ApplicationCommandOption>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<crate::channel::ChannelType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub autocomplete: bool,
+}
+
+impl ApplicationCommandOption {
+    pub fn new(kind: ApplicationCommandOptionType, name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            name_localizations: None,
+            description: description.into(),
+            description_localizations: None,
+            required: false,
+            choices: Vec::new(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+            autocomplete: false,
+        }
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// `value` must match the option type: a string, integer or number.
+    pub fn choice(mut self, name: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.choices.push(ApplicationCommandOptionChoice {
+            name: name.into(),
+            name_localizations: None,
+            value: value.into(),
+        });
+        self
+    }
+
+    pub fn option(mut self, option: ApplicationCommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn channel_types(mut self, types: Vec<crate::channel::ChannelType>) -> Self {
+        self.channel_types = types;
+        self
+    }
+
+    pub fn min_value(mut self, value: f64) -> Self {
+        self.min_value = Some(value);
+        self
+    }
+
+    pub fn max_value(mut self, value: f64) -> Self {
+        self.max_value = Some(value);
+        self
+    }
+
+    pub fn min_length(mut self, length: u16) -> Self {
+        self.min_length = Some(length);
+        self
+    }
+
+    pub fn max_length(mut self, length: u16) -> Self {
+        self.max_length = Some(length);
+        self
+    }
+
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete = autocomplete;
+        self
+    }
+
+    pub fn name_localization(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.name_localizations
+            .get_or_insert_with(Localizations::new)
+            .insert(locale.into(), name.into());
+        self
+    }
+
+    pub fn description_localization(mut self, locale: impl Into<String>, description: impl Into<String>) -> Self {
+        self.description_localizations
+            .get_or_insert_with(Localizations::new)
+            .insert(locale.into(), description.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationCommandOptionChoice {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    pub value: serde_json::Value,
+}
+
+/// Body for creating, editing or bulk-overwriting a command.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateApplicationCommand {
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandType,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    /// Empty for user and message commands.
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<Localizations>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ApplicationCommandOption>,
+    pub default_member_permissions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dm_permission: Option<bool>,
+    pub nsfw: bool,
+}
+
+impl CreateApplicationCommand {
+    /// A slash command.
+    pub fn chat_input(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self::new(ApplicationCommandType::ChatInput, name, description.into())
+    }
+
+    /// A command in the "Apps" menu of a user.
+    pub fn user(name: impl Into<String>) -> Self {
+        Self::new(ApplicationCommandType::User, name, String::new())
+    }
+
+    /// A command in the "Apps" menu of a message.
+    pub fn message(name: impl Into<String>) -> Self {
+        Self::new(ApplicationCommandType::Message, name, String::new())
+    }
+
+    fn new(kind: ApplicationCommandType, name: impl Into<String>, description: String) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            name_localizations: None,
+            description,
+            description_localizations: None,
+            options: Vec::new(),
+            default_member_permissions: None,
+            dm_permission: None,
+            nsfw: false,
+        }
+    }
+
+    pub fn option(mut self, option: ApplicationCommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Permission bitset required to see the command; `"0"` hides it from
+    /// everyone but administrators.
+    pub fn default_member_permissions(mut self, permissions: impl Into<String>) -> Self {
+        self.default_member_permissions = Some(permissions.into());
+        self
+    }
+
+    /// Global commands only.
+    pub fn dm_permission(mut self, allowed: bool) -> Self {
+        self.dm_permission = Some(allowed);
+        self
+    }
+
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = nsfw;
+        self
+    }
+
+    pub fn name_localization(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.name_localizations
+            .get_or_insert_with(Localizations::new)
+            .insert(locale.into(), name.into());
+        self
+    }
+
+    pub fn description_localization(mut self, locale: impl Into<String>, description: impl Into<String>) -> Self {
+        self.description_localizations
+            .get_or_insert_with(Localizations::new)
+            .insert(locale.into(), description.into());
+        self
+    }
+
+    /// Whether `command` is already registered as declared here, so that
+    /// overwriting it would change nothing. Localizations are compared too,
+    /// so `command` should be fetched with `with_localizations`.
+    pub fn matches(&self, command: &ApplicationCommand) -> bool {
+        self.kind == command.kind
+            && self.name == command.name
+            && self.description == command.description
+            && localizations(&self.name_localizations) == localizations(&command.name_localizations)
+            && localizations(&self.description_localizations) == localizations(&command.description_localizations)
+            && options_match(&self.options, &command.options)
+            && self.default_member_permissions == command.default_member_permissions
+            && self.dm_permission.unwrap_or(true) == command.dm_permission.unwrap_or(true)
+            && self.nsfw == command.nsfw
+    }
+}
+
+// Discord returns `{}` or `null` for unset localizations, depending on the route.
+fn localizations(l: &Option<Localizations>) -> Option<&Localizations> {
+    l.as_ref().filter(|l| !l.is_empty())
+}
+
+// Number choices may come back as `6.0` for a declared `6`.
+fn choice_values_match(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn options_match(a: &[ApplicationCommandOption], b: &[ApplicationCommandOption]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(a, b)| {
+            a.kind == b.kind
+                && a.name == b.name
+                && a.description == b.description
+                && localizations(&a.name_localizations) == localizations(&b.name_localizations)
+                && localizations(&a.description_localizations) == localizations(&b.description_localizations)
+                && a.required == b.required
+                && a.choices.len() == b.choices.len()
+                && a.choices.iter().zip(&b.choices).all(|(a, b)| {
+                    a.name == b.name
+                        && choice_values_match(&a.value, &b.value)
+                        && localizations(&a.name_localizations) == localizations(&b.name_localizations)
+                })
+                && options_match(&a.options, &b.options)
+                && a.channel_types == b.channel_types
+                && a.min_value == b.min_value
+                && a.max_value == b.max_value
+                && a.min_length == b.min_length
+                && a.max_length == b.max_length
+                && a.autocomplete == b.autocomplete
+        })
+}
+
+/// Whether overwriting `registered` with `declared` would change anything.
+/// Order doesn't matter; commands are matched by type and name.
+pub fn commands_differ(declared: &[CreateApplicationCommand], registered: &[ApplicationCommand]) -> bool {
+    declared.len() != registered.len()
+        || declared.iter().any(|d| {
+            !registered
+                .iter()
+                .any(|r| r.kind == d.kind && r.name == d.name && d.matches(r))
+        })
+}
+
+/// Permissions of all or one of an application's commands in a guild.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildApplicationCommandPermissions {
+    /// The command, or the application id for the application-wide defaults.
+    pub id: Snowflake,
+    pub application_id: Snowflake,
+    pub guild_id: Snowflake,
+    pub permissions: Vec<ApplicationCommandPermission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationCommandPermission {
+    /// Role, user or channel id. The guild id stands for `@everyone`, the
+    /// guild id minus one for all channels.
+    pub id: Snowflake,
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandPermissionType,
+    pub permission: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum ApplicationCommandPermissionType {
+    Role = 1,
+    User = 2,
+    Channel = 3,
+    Unknown(u8),
+}
+
+impl From<u8> for ApplicationCommandPermissionType {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => ApplicationCommandPermissionType::Role,
+            2 => ApplicationCommandPermissionType::User,
+            3 => ApplicationCommandPermissionType::Channel,
+            _ => ApplicationCommandPermissionType::Unknown(v),
+        }
+    }
+}
+
+impl From<ApplicationCommandPermissionType> for u8 {
+    fn from(v: ApplicationCommandPermissionType) -> u8 {
+        match v {
+            ApplicationCommandPermissionType::Role => 1,
+            ApplicationCommandPermissionType::User => 2,
+            ApplicationCommandPermissionType::Channel => 3,
+            ApplicationCommandPermissionType::Unknown(v) => v,
+        }
+    }
+}
+
+/// Body for `PUT /applications/{application.id}/guilds/{guild.id}/commands/{command.id}/permissions`.
+#[derive(Debug, Clone, Serialize)]
+pub struct EditApplicationCommandPermissions {
+    pub permissions: Vec<ApplicationCommandPermission>,
 }
@@ -14,11 +14,16 @@ pub mod interaction;
 pub mod event;
 pub mod component;
 pub mod presence;
+pub mod webhook;
+pub mod cdn;
+pub mod image;
+pub mod audit_log;
+pub mod auto_moderation;
 
 // Re-export common types
 pub use user::User;
 pub use role::Role;
-pub use guild::Guild;
+pub use guild::{Guild, Ban};
 pub use member::Member;
 pub use channel::Channel;
 pub use message::Message;
@@ -26,4 +31,7 @@ pub use interaction::{Interaction, InteractionResponse, InteractionType, Interac
 pub use event::Event;
 pub use component::{Component, ComponentType, Button, ActionRow, SelectMenu};
 pub use presence::{PresenceUpdate, Activity, ActivityType, PresenceStatus};
+pub use webhook::Webhook;
+pub use audit_log::{AuditLog, AuditLogEntry, AuditLogEvent};
+pub use auto_moderation::AutoModerationRule;
 pub use discord_rs_core::Snowflake;
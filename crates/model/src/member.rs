@@ -1,6 +1,7 @@
 use discord_rs_core::Snowflake;
 use serde::{Deserialize, Serialize};
 use crate::user::User;
+use crate::cdn::{self, ImageUrl};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Member {
@@ -22,3 +23,103 @@ pub struct Member {
     pub permissions: Option<String>,
     pub communication_disabled_until: Option<String>,
 }
+
+impl Member {
+    /// The guild-specific avatar. `None` if none is set or `user` is missing.
+    pub fn avatar_url(&self, guild_id: Snowflake) -> Option<ImageUrl> {
+        let user = self.user.as_ref()?;
+        let hash = self.avatar.as_deref()?;
+        Some(cdn::guild_member_avatar(guild_id, user.id, hash))
+    }
+
+    /// The guild avatar, falling back to the user's (or default) avatar.
+    pub fn display_avatar_url(&self, guild_id: Snowflake) -> Option<ImageUrl> {
+        self.avatar_url(guild_id)
+            .or_else(|| self.user.as_ref().map(User::display_avatar_url))
+    }
+}
+
+/// Body for `PATCH /guilds/{guild.id}/members/{user.id}`.
+///
+/// Fields set to `Some(None)` are sent as `null`, which clears them on Discord's side
+/// (e.g. removing a timeout or disconnecting from voice).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyGuildMember {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nick: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mute: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deaf: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Option<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub communication_disabled_until: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<i32>,
+}
+
+impl ModifyGuildMember {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn nick(mut self, nick: impl Into<String>) -> Self {
+        self.nick = Some(Some(nick.into()));
+        self
+    }
+
+    pub fn reset_nick(mut self) -> Self {
+        self.nick = Some(None);
+        self
+    }
+
+    pub fn roles(mut self, roles: Vec<Snowflake>) -> Self {
+        self.roles = Some(roles);
+        self
+    }
+
+    pub fn mute(mut self, mute: bool) -> Self {
+        self.mute = Some(mute);
+        self
+    }
+
+    pub fn deafen(mut self, deaf: bool) -> Self {
+        self.deaf = Some(deaf);
+        self
+    }
+
+    /// Moves the member to another voice channel.
+    pub fn move_to(mut self, channel_id: Snowflake) -> Self {
+        self.channel_id = Some(Some(channel_id));
+        self
+    }
+
+    /// Disconnects the member from voice.
+    pub fn disconnect(mut self) -> Self {
+        self.channel_id = Some(None);
+        self
+    }
+
+    /// Times the member out until the given ISO8601 timestamp (at most 28 days ahead).
+    pub fn timeout_until(mut self, timestamp: impl Into<String>) -> Self {
+        self.communication_disabled_until = Some(Some(timestamp.into()));
+        self
+    }
+
+    pub fn remove_timeout(mut self) -> Self {
+        self.communication_disabled_until = Some(None);
+        self
+    }
+}
+
+/// Query for `GET /guilds/{guild.id}/members`. Results are ordered by user id.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListGuildMembers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u16>, // 1-1000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Snowflake>,
+}
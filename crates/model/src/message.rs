@@ -229,3 +229,44 @@ pub struct StickerItem {
     pub name: String,
     pub format_type: i32,
 }
+
+/// Query parameters for `GET /channels/{channel.id}/messages`.
+///
+/// Only one of `around`, `before` and `after` may be set per request.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetMessages {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub around: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>, // 1-100, defaults to 50
+}
+
+impl GetMessages {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn around(mut self, id: Snowflake) -> Self {
+        self.around = Some(id);
+        self
+    }
+
+    pub fn before(mut self, id: Snowflake) -> Self {
+        self.before = Some(id);
+        self
+    }
+
+    pub fn after(mut self, id: Snowflake) -> Self {
+        self.after = Some(id);
+        self
+    }
+
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
@@ -1,4 +1,3 @@
-use discord_rs_core::Snowflake;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
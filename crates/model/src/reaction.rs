@@ -1,9 +1,167 @@
+use discord_rs_core::Snowflake;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 use crate::emoji::Emoji;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Reaction {
+    /// Normal and burst reactions combined.
     pub count: i32,
+    #[serde(default)]
+    pub count_details: ReactionCountDetails,
     pub me: bool,
+    #[serde(default)]
+    pub me_burst: bool,
     pub emoji: Emoji,
+    /// Hex colors of burst reactions.
+    #[serde(default)]
+    pub burst_colors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReactionCountDetails {
+    pub burst: i32,
+    pub normal: i32,
+}
+
+/// Emoji of a reaction, as used in the reaction endpoints.
+///
+/// Parses from a unicode emoji, `name:id` or a `<:name:id>` / `<a:name:id>`
+/// mention.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReactionType {
+    Unicode(String),
+    Custom {
+        id: Snowflake,
+        /// Optional for requests, but shown in `Display`.
+        name: Option<String>,
+        animated: bool,
+    },
+}
+
+impl ReactionType {
+    pub fn unicode(emoji: impl Into<String>) -> Self {
+        ReactionType::Unicode(emoji.into())
+    }
+
+    pub fn custom(name: impl Into<String>, id: Snowflake) -> Self {
+        ReactionType::Custom {
+            id,
+            name: Some(name.into()),
+            animated: false,
+        }
+    }
+
+    /// The `{emoji}` path segment before percent-encoding: the emoji itself
+    /// or `name:id`.
+    pub fn as_route_component(&self) -> String {
+        match self {
+            ReactionType::Unicode(emoji) => emoji.clone(),
+            ReactionType::Custom { id, name, .. } => {
+                format!("{}:{}", name.as_deref().unwrap_or("_"), id)
+            }
+        }
+    }
+}
+
+impl From<&Emoji> for ReactionType {
+    fn from(emoji: &Emoji) -> Self {
+        match emoji.id {
+            Some(id) => ReactionType::Custom {
+                id,
+                name: emoji.name.clone(),
+                animated: emoji.animated,
+            },
+            None => ReactionType::Unicode(emoji.name.clone().unwrap_or_default()),
+        }
+    }
+}
+
+impl From<char> for ReactionType {
+    fn from(emoji: char) -> Self {
+        ReactionType::Unicode(emoji.to_string())
+    }
+}
+
+impl FromStr for ReactionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (inner, animated) = match s.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+            Some(inner) => match inner.strip_prefix("a:") {
+                Some(rest) => (rest, true),
+                None => (inner.strip_prefix(':').unwrap_or(inner), false),
+            },
+            None => (s, false),
+        };
+        if let Some((name, id)) = inner.rsplit_once(':') {
+            if let Ok(id) = id.parse::<u64>() {
+                return Ok(ReactionType::Custom {
+                    id: Snowflake::new(id),
+                    name: Some(name.to_string()).filter(|n| !n.is_empty()),
+                    animated,
+                });
+            }
+        }
+        Ok(ReactionType::Unicode(s.to_string()))
+    }
+}
+
+impl fmt::Display for ReactionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReactionType::Unicode(emoji) => f.write_str(emoji),
+            ReactionType::Custom { id, name, animated } => write!(
+                f,
+                "<{}:{}:{}>",
+                if *animated { "a" } else { "" },
+                name.as_deref().unwrap_or("_"),
+                id
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum ReactionKind {
+    #[default]
+    Normal = 0,
+    /// Super reactions.
+    Burst = 1,
+    Unknown(u8),
+}
+
+impl From<u8> for ReactionKind {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => ReactionKind::Normal,
+            1 => ReactionKind::Burst,
+            _ => ReactionKind::Unknown(v),
+        }
+    }
+}
+
+impl From<ReactionKind> for u8 {
+    fn from(v: ReactionKind) -> u8 {
+        match v {
+            ReactionKind::Normal => 0,
+            ReactionKind::Burst => 1,
+            ReactionKind::Unknown(v) => v,
+        }
+    }
+}
+
+/// Query for `GET /channels/{channel.id}/messages/{message.id}/reactions/{emoji}`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetReactions {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ReactionKind>,
+    /// Users with a higher id than this.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>, // 1-100
 }
@@ -1,5 +1,7 @@
 use discord_rs_core::Snowflake;
 use serde::{Deserialize, Serialize};
+use crate::cdn::{self, ImageUrl};
+use crate::image::ImageData;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Role {
@@ -17,6 +19,12 @@ pub struct Role {
     pub tags: Option<RoleTags>,
 }
 
+impl Role {
+    pub fn icon_url(&self) -> Option<ImageUrl> {
+        self.icon.as_deref().map(|hash| cdn::role_icon(self.id, hash))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoleTags {
     pub bot_id: Option<Snowflake>,
@@ -26,3 +34,67 @@ pub struct RoleTags {
     pub available_for_purchase: Option<serde_json::Value>,
     pub guild_connections: Option<serde_json::Value>,
 }
+
+/// Body for creating (`POST /guilds/{guild.id}/roles`) or modifying a role.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditRole {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hoist: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>, // Data URI
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unicode_emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mentionable: Option<bool>,
+}
+
+impl EditRole {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn permissions(mut self, permissions: impl Into<String>) -> Self {
+        self.permissions = Some(permissions.into());
+        self
+    }
+
+    pub fn color(mut self, color: u32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn hoist(mut self, hoist: bool) -> Self {
+        self.hoist = Some(hoist);
+        self
+    }
+
+    pub fn mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = Some(mentionable);
+        self
+    }
+
+    /// Requires the guild to have the `ROLE_ICONS` feature.
+    pub fn icon(mut self, image: &ImageData) -> Self {
+        self.icon = Some(image.to_data_uri());
+        self
+    }
+}
+
+/// One entry of the body for `PATCH /guilds/{guild.id}/roles`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModifyRolePosition {
+    pub id: Snowflake,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+}
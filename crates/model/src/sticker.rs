@@ -1,5 +1,7 @@
+use discord_rs_core::traits::FileUpload;
 use discord_rs_core::Snowflake;
 use serde::{Deserialize, Serialize};
+use crate::cdn::{self, ImageUrl};
 use crate::user::User;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,6 +12,7 @@ pub struct Sticker {
     pub description: Option<String>,
     pub tags: String, // CSV
     pub asset: Option<String>, // Deprecated
+    #[serde(rename = "type")]
     pub type_id: StickerType, // 'type' is reserved
     pub format_type: StickerFormatType,
     #[serde(default)]
@@ -19,6 +22,12 @@ pub struct Sticker {
     pub sort_value: Option<i32>,
 }
 
+impl Sticker {
+    pub fn url(&self) -> ImageUrl {
+        cdn::sticker(self.id, self.format_type)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
 #[serde(try_from = "u8", into = "u8")]
@@ -81,3 +90,83 @@ impl From<StickerFormatType> for u8 {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickerPack {
+    pub id: Snowflake,
+    pub stickers: Vec<Sticker>,
+    pub name: String,
+    pub sku_id: Snowflake,
+    pub cover_sticker_id: Option<Snowflake>,
+    pub description: String,
+    pub banner_asset_id: Option<Snowflake>,
+}
+
+/// Response of `GET /sticker-packs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickerPacks {
+    pub sticker_packs: Vec<StickerPack>,
+}
+
+/// Form for `POST /guilds/{guild.id}/stickers`, sent as multipart rather
+/// than JSON.
+#[derive(Debug, Clone)]
+pub struct CreateGuildSticker {
+    /// 2-30 characters.
+    pub name: String,
+    /// Empty or 2-100 characters.
+    pub description: String,
+    /// Autocomplete/suggestion tags, up to 200 characters.
+    pub tags: String,
+    /// PNG, APNG, GIF or Lottie JSON, at most 512 KiB.
+    pub file: FileUpload,
+}
+
+impl CreateGuildSticker {
+    pub fn new(name: impl Into<String>, tags: impl Into<String>, file: FileUpload) -> Self {
+        Self {
+            name: name.into(),
+            description: String::new(),
+            tags: tags.into(),
+            file,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+}
+
+/// Body for `PATCH /guilds/{guild.id}/stickers/{sticker.id}`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyGuildSticker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// `Some(None)` clears the description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+}
+
+impl ModifyGuildSticker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: Option<String>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn tags(mut self, tags: impl Into<String>) -> Self {
+        self.tags = Some(tags.into());
+        self
+    }
+}
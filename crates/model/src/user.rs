@@ -1,5 +1,6 @@
 use discord_rs_core::Snowflake;
 use serde::{Deserialize, Serialize};
+use crate::cdn::{self, ImageUrl};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
@@ -28,6 +29,25 @@ pub struct User {
     pub public_flags: u64,
 }
 
+impl User {
+    pub fn avatar_url(&self) -> Option<ImageUrl> {
+        self.avatar.as_deref().map(|hash| cdn::user_avatar(self.id, hash))
+    }
+
+    pub fn default_avatar_url(&self) -> ImageUrl {
+        cdn::default_user_avatar(self.id, &self.discriminator)
+    }
+
+    /// The avatar, or the default one if none is set.
+    pub fn display_avatar_url(&self) -> ImageUrl {
+        self.avatar_url().unwrap_or_else(|| self.default_avatar_url())
+    }
+
+    pub fn banner_url(&self) -> Option<ImageUrl> {
+        self.banner.as_deref().map(|hash| cdn::user_banner(self.id, hash))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PartialUser {
     pub id: Snowflake,
@@ -35,3 +55,16 @@ pub struct PartialUser {
     pub discriminator: Option<String>,
     pub avatar: Option<String>,
 }
+
+/// Query for `GET /users/@me/guilds`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GetCurrentUserGuilds {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>, // 1-200
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_counts: Option<bool>,
+}
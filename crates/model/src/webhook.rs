@@ -0,0 +1,337 @@
+use crate::attachment::PartialAttachment;
+use crate::cdn::{self, ImageUrl};
+use crate::embed::Embed;
+use crate::image::ImageData;
+use crate::message::AllowedMentions;
+use crate::user::User;
+use discord_rs_core::traits::FileUpload;
+use discord_rs_core::Snowflake;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: Snowflake,
+    #[serde(rename = "type")]
+    pub kind: WebhookType,
+    pub guild_id: Option<Snowflake>,
+    pub channel_id: Option<Snowflake>,
+    /// Creator; not returned when fetched by token.
+    pub user: Option<User>,
+    pub name: Option<String>,
+    pub avatar: Option<String>,
+    /// Only incoming webhooks have a token.
+    pub token: Option<String>,
+    pub application_id: Option<Snowflake>,
+    /// Guild of the followed channel, for channel follower webhooks.
+    pub source_guild: Option<WebhookSourceGuild>,
+    /// The followed channel, for channel follower webhooks.
+    pub source_channel: Option<WebhookSourceChannel>,
+    /// Execute URL, only returned for OAuth2 `webhook.incoming` webhooks.
+    pub url: Option<String>,
+}
+
+impl Webhook {
+    pub fn avatar_url(&self) -> Option<ImageUrl> {
+        self.avatar.as_deref().map(|hash| cdn::user_avatar(self.id, hash))
+    }
+
+    /// `https://discord.com/api/webhooks/{id}/{token}`, if the token is known.
+    pub fn execute_url(&self) -> Option<String> {
+        self.token
+            .as_ref()
+            .map(|token| format!("https://discord.com/api/webhooks/{}/{}", self.id, token))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
+pub enum WebhookType {
+    /// Posts messages to a channel with its token.
+    Incoming = 1,
+    /// Crossposts messages of a followed announcement channel.
+    ChannelFollower = 2,
+    /// Used for interactions.
+    Application = 3,
+    Unknown(u8),
+}
+
+impl From<u8> for WebhookType {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => WebhookType::Incoming,
+            2 => WebhookType::ChannelFollower,
+            3 => WebhookType::Application,
+            _ => WebhookType::Unknown(v),
+        }
+    }
+}
+
+impl From<WebhookType> for u8 {
+    fn from(v: WebhookType) -> u8 {
+        match v {
+            WebhookType::Incoming => 1,
+            WebhookType::ChannelFollower => 2,
+            WebhookType::Application => 3,
+            WebhookType::Unknown(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSourceGuild {
+    pub id: Snowflake,
+    pub name: String,
+    pub icon: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSourceChannel {
+    pub id: Snowflake,
+    pub name: String,
+}
+
+/// Body for `POST /channels/{channel.id}/webhooks`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateWebhook {
+    /// 1-80 characters; may not contain "clyde" or "discord".
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>, // Data URI
+}
+
+impl CreateWebhook {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            avatar: None,
+        }
+    }
+
+    pub fn avatar(mut self, image: &ImageData) -> Self {
+        self.avatar = Some(image.to_data_uri());
+        self
+    }
+}
+
+/// Body for `PATCH /webhooks/{webhook.id}`.
+///
+/// `channel_id` is ignored when modifying with the webhook token.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ModifyWebhook {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// `Some(None)` removes the avatar.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<Option<String>>, // Data URI
+    /// Moves the webhook to another channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+}
+
+impl ModifyWebhook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// `None` removes the avatar.
+    pub fn avatar(mut self, image: Option<&ImageData>) -> Self {
+        self.avatar = Some(image.map(ImageData::to_data_uri));
+        self
+    }
+
+    pub fn channel_id(mut self, channel_id: Snowflake) -> Self {
+        self.channel_id = Some(channel_id);
+        self
+    }
+}
+
+/// Body for `POST /channels/{channel.id}/followers`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FollowAnnouncementChannel {
+    /// Channel the followed channel's messages are crossposted to.
+    pub webhook_channel_id: Snowflake,
+}
+
+/// Result of following an announcement channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowedChannel {
+    /// The followed (source) channel.
+    pub channel_id: Snowflake,
+    /// The channel follower webhook created in the target channel.
+    pub webhook_id: Snowflake,
+}
+
+/// Body for `POST /webhooks/{webhook.id}/{webhook.token}`.
+///
+/// Files added with `add_file` are not serialized; they are sent as
+/// `files[n]` parts next to this payload.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExecuteWebhook {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Overrides the webhook's default name for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Overrides the webhook's default avatar for this message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<PartialAttachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+    /// Creates a forum/media channel post with this name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_name: Option<String>,
+    /// Tags of the forum/media channel post created with `thread_name`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_tags: Option<Vec<Snowflake>>,
+    #[serde(skip)]
+    pub files: Vec<FileUpload>,
+}
+
+impl ExecuteWebhook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    pub fn avatar_url(mut self, url: impl Into<String>) -> Self {
+        self.avatar_url = Some(url.into());
+        self
+    }
+
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = Some(tts);
+        self
+    }
+
+    pub fn add_embed(mut self, embed: impl Into<Embed>) -> Self {
+        self.embeds.get_or_insert_with(Vec::new).push(embed.into());
+        self
+    }
+
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    pub fn thread_name(mut self, name: impl Into<String>) -> Self {
+        self.thread_name = Some(name.into());
+        self
+    }
+
+    pub fn applied_tags(mut self, tags: Vec<Snowflake>) -> Self {
+        self.applied_tags = Some(tags);
+        self
+    }
+
+    pub fn add_file(mut self, file: FileUpload) -> Self {
+        push_file(&mut self.attachments, &mut self.files, file);
+        self
+    }
+}
+
+/// Query for executing a webhook.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExecuteWebhookQuery {
+    /// Wait for the message to be created and return it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait: Option<bool>,
+    /// Post into this thread of the webhook's channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<Snowflake>,
+}
+
+impl ExecuteWebhookQuery {
+    pub fn wait() -> Self {
+        Self {
+            wait: Some(true),
+            thread_id: None,
+        }
+    }
+
+    pub fn thread_id(mut self, thread_id: Snowflake) -> Self {
+        self.thread_id = Some(thread_id);
+        self
+    }
+}
+
+/// Body for `PATCH /webhooks/{webhook.id}/{webhook.token}/messages/{message.id}`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditWebhookMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<serde_json::Value>>,
+    /// Attachments to keep. Existing ones not listed here are removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<PartialAttachment>>,
+    #[serde(skip)]
+    pub files: Vec<FileUpload>,
+}
+
+impl EditWebhookMessage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Some(embeds);
+        self
+    }
+
+    pub fn keep_attachment(mut self, id: Snowflake) -> Self {
+        self.attachments.get_or_insert_with(Vec::new).push(PartialAttachment {
+            id,
+            filename: None,
+            description: None,
+        });
+        self
+    }
+
+    pub fn add_file(mut self, file: FileUpload) -> Self {
+        push_file(&mut self.attachments, &mut self.files, file);
+        self
+    }
+}
+
+fn push_file(attachments: &mut Option<Vec<PartialAttachment>>, files: &mut Vec<FileUpload>, file: FileUpload) {
+    attachments.get_or_insert_with(Vec::new).push(PartialAttachment {
+        id: Snowflake::new(files.len() as u64),
+        filename: Some(file.filename.clone()),
+        description: None,
+    });
+    files.push(file);
+}
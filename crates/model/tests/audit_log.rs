@@ -0,0 +1,98 @@
+use discord_rs_model::audit_log::{AuditLog, AuditLogChangeKey, AuditLogEvent, GetGuildAuditLog};
+use discord_rs_model::{Event, Snowflake};
+use serde_json::json;
+
+#[test]
+fn test_deserializes_entries_with_changes_and_options() {
+    let log: AuditLog = serde_json::from_value(json!({
+        "audit_log_entries": [
+            {
+                "id": "20",
+                "target_id": "5",
+                "user_id": "3",
+                "action_type": 25,
+                "changes": [
+                    { "key": "$add", "new_value": [{ "id": "7", "name": "mod" }] },
+                    { "key": "nick", "old_value": "a", "new_value": "b" },
+                    { "key": "brand_new_key", "new_value": 1 }
+                ],
+                "reason": "promotion"
+            },
+            {
+                "id": "19",
+                "target_id": "6",
+                "user_id": "3",
+                "action_type": 72,
+                "options": { "channel_id": "9", "count": "4" }
+            }
+        ],
+        "users": [{ "id": "3", "username": "alice", "discriminator": "0" }],
+        "webhooks": [],
+        "threads": []
+    }))
+    .unwrap();
+
+    let update = &log.audit_log_entries[0];
+    assert_eq!(update.action_type, AuditLogEvent::MemberRoleUpdate);
+    assert_eq!(log.user(update.user_id.unwrap()).unwrap().username, "alice");
+    let nick = update.change(&AuditLogChangeKey::Nick).unwrap();
+    assert_eq!(nick.old_value_as::<String>().as_deref(), Some("a"));
+    assert_eq!(nick.new_value_as::<String>().as_deref(), Some("b"));
+    assert_eq!(update.changes[0].key, AuditLogChangeKey::RoleAdd);
+    assert_eq!(update.changes[2].key, AuditLogChangeKey::Other("brand_new_key".to_string()));
+
+    let delete = &log.audit_log_entries[1];
+    assert_eq!(delete.action_type, AuditLogEvent::MessageDelete);
+    let options = delete.options.as_ref().unwrap();
+    assert_eq!(options.channel_id, Some(Snowflake(9)));
+    assert_eq!(options.count.as_deref(), Some("4"));
+}
+
+#[test]
+fn test_action_types_and_change_keys_round_trip() {
+    assert_eq!(u8::from(AuditLogEvent::AutoModerationBlockMessage), 143);
+    assert_eq!(AuditLogEvent::from(191), AuditLogEvent::HomeSettingsUpdate);
+    assert_eq!(AuditLogEvent::from(250), AuditLogEvent::Unknown(250));
+
+    for key in ["$remove", "permission_overwrites", "something_else"] {
+        let parsed: AuditLogChangeKey = serde_json::from_value(json!(key)).unwrap();
+        assert_eq!(parsed.as_str(), key);
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), json!(key));
+    }
+}
+
+#[test]
+fn test_query_skips_unset_filters() {
+    let query = GetGuildAuditLog::new()
+        .user_id(Snowflake(3))
+        .action_type(AuditLogEvent::MemberBanAdd);
+
+    assert_eq!(
+        serde_json::to_value(&query).unwrap(),
+        json!({ "user_id": "3", "action_type": 22 })
+    );
+}
+
+#[test]
+fn test_entry_create_event_carries_the_guild_id() {
+    let event: Event = serde_json::from_value(json!({
+        "t": "GUILD_AUDIT_LOG_ENTRY_CREATE",
+        "d": {
+            "id": "20",
+            "guild_id": "1",
+            "target_id": "5",
+            "user_id": "3",
+            "action_type": 22,
+            "reason": null
+        }
+    }))
+    .unwrap();
+
+    match event {
+        Event::GuildAuditLogEntryCreate(entry) => {
+            assert_eq!(entry.guild_id, Some(Snowflake(1)));
+            assert_eq!(entry.action_type, AuditLogEvent::MemberBanAdd);
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+}
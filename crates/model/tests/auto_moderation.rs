@@ -0,0 +1,100 @@
+use discord_rs_model::auto_moderation::{
+    AutoModerationAction, AutoModerationActionType, AutoModerationEventType, AutoModerationRule,
+    AutoModerationTriggerMetadata, AutoModerationTriggerType, CreateAutoModerationRule, KeywordPresetType,
+    ModifyAutoModerationRule,
+};
+use discord_rs_model::{Event, Snowflake};
+use serde_json::json;
+
+#[test]
+fn test_deserializes_a_keyword_rule() {
+    let rule: AutoModerationRule = serde_json::from_value(json!({
+        "id": "9",
+        "guild_id": "1",
+        "name": "No swearing",
+        "creator_id": "3",
+        "event_type": 1,
+        "trigger_type": 1,
+        "trigger_metadata": { "keyword_filter": ["darn*"], "regex_patterns": [], "allow_list": ["darnit"] },
+        "actions": [
+            { "type": 1, "metadata": { "custom_message": "Please keep it clean" } },
+            { "type": 2, "metadata": { "channel_id": "5" } },
+            { "type": 3, "metadata": { "duration_seconds": 60 } }
+        ],
+        "enabled": true,
+        "exempt_roles": ["7"],
+        "exempt_channels": []
+    }))
+    .unwrap();
+
+    assert_eq!(rule.trigger_type, AutoModerationTriggerType::Keyword);
+    assert_eq!(rule.trigger_metadata.keyword_filter, vec!["darn*"]);
+    assert_eq!(rule.trigger_metadata.allow_list, vec!["darnit"]);
+    assert_eq!(
+        rule.actions,
+        vec![
+            AutoModerationAction::block_message(Some("Please keep it clean".to_string())),
+            AutoModerationAction::send_alert_message(Snowflake(5)),
+            AutoModerationAction::timeout(60),
+        ]
+    );
+    assert_eq!(rule.exempt_roles, vec![Snowflake(7)]);
+}
+
+#[test]
+fn test_create_body_leaves_out_unset_metadata() {
+    let rule = CreateAutoModerationRule::new(
+        "Presets",
+        AutoModerationEventType::MessageSend,
+        AutoModerationTriggerType::KeywordPreset,
+    )
+    .trigger_metadata(AutoModerationTriggerMetadata::new().preset(KeywordPresetType::Slurs))
+    .action(AutoModerationAction::block_message(None))
+    .enabled(true);
+
+    assert_eq!(
+        serde_json::to_value(&rule).unwrap(),
+        json!({
+            "name": "Presets",
+            "event_type": 1,
+            "trigger_type": 4,
+            "trigger_metadata": { "presets": [3] },
+            "actions": [{ "type": 1 }],
+            "enabled": true
+        })
+    );
+
+    let modify = ModifyAutoModerationRule::new().enabled(false).exempt_channels(vec![]);
+    assert_eq!(
+        serde_json::to_value(&modify).unwrap(),
+        json!({ "enabled": false, "exempt_channels": [] })
+    );
+}
+
+#[test]
+fn test_action_execution_event() {
+    let event: Event = serde_json::from_value(json!({
+        "t": "AUTO_MODERATION_ACTION_EXECUTION",
+        "d": {
+            "guild_id": "1",
+            "action": { "type": 4 },
+            "rule_id": "9",
+            "rule_trigger_type": 6,
+            "user_id": "3",
+            "content": "",
+            "matched_keyword": "spam*",
+            "matched_content": null
+        }
+    }))
+    .unwrap();
+
+    match event {
+        Event::AutoModerationActionExecution(execution) => {
+            assert_eq!(execution.action.kind, AutoModerationActionType::BlockMemberInteraction);
+            assert_eq!(execution.rule_trigger_type, AutoModerationTriggerType::MemberProfile);
+            assert_eq!(execution.channel_id, None);
+            assert_eq!(execution.matched_keyword.as_deref(), Some("spam*"));
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+}
@@ -0,0 +1,82 @@
+use discord_rs_model::cdn::{self, ImageFormat, ImageSize};
+use discord_rs_model::emoji::Emoji;
+use discord_rs_model::sticker::StickerFormatType;
+use discord_rs_model::{Snowflake, User};
+use serde_json::json;
+
+fn user(avatar: Option<&str>, discriminator: &str) -> User {
+    serde_json::from_value(json!({
+        "id": "80351110224678912",
+        "username": "Nelly",
+        "discriminator": discriminator,
+        "avatar": avatar,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_animated_hashes_default_to_gif() {
+    let user = user(Some("a_1269e74af4df7417b13759eae50c83dc"), "0");
+    let avatar = user.avatar_url().unwrap();
+
+    assert!(avatar.is_animated());
+    assert_eq!(
+        avatar.url(),
+        "https://cdn.discordapp.com/avatars/80351110224678912/a_1269e74af4df7417b13759eae50c83dc.gif"
+    );
+    assert_eq!(avatar.static_image().current_format(), ImageFormat::Png);
+}
+
+#[test]
+fn test_static_hashes_reject_gif() {
+    let avatar = cdn::user_avatar(Snowflake(1), "abc");
+
+    assert!(avatar.clone().format(ImageFormat::Gif).is_err());
+    let webp = avatar.format(ImageFormat::WebP).unwrap().size(ImageSize::new(64).unwrap());
+    assert_eq!(webp.url(), "https://cdn.discordapp.com/avatars/1/abc.webp?size=64");
+}
+
+#[test]
+fn test_sizes_must_be_powers_of_two_in_range() {
+    assert!(ImageSize::new(16).is_ok());
+    assert!(ImageSize::new(4096).is_ok());
+    assert!(ImageSize::new(8).is_err());
+    assert!(ImageSize::new(100).is_err());
+    assert!(ImageSize::new(8192).is_err());
+}
+
+#[test]
+fn test_default_avatar_uses_id_or_discriminator() {
+    // (80351110224678912 >> 22) % 6
+    assert_eq!(
+        user(None, "0").display_avatar_url().url(),
+        "https://cdn.discordapp.com/embed/avatars/5.png"
+    );
+    assert_eq!(
+        user(None, "1337").display_avatar_url().url(),
+        "https://cdn.discordapp.com/embed/avatars/2.png"
+    );
+    assert!(user(None, "0").default_avatar_url().format(ImageFormat::Jpeg).is_err());
+}
+
+#[test]
+fn test_emoji_and_sticker_urls() {
+    let emoji: Emoji = serde_json::from_value(json!({ "id": "41771983429993937", "name": "LUL", "animated": true })).unwrap();
+    assert_eq!(emoji.url().unwrap().url(), "https://cdn.discordapp.com/emojis/41771983429993937.gif");
+
+    let unicode: Emoji = serde_json::from_value(json!({ "id": null, "name": "🔥" })).unwrap();
+    assert!(unicode.url().is_none());
+
+    assert_eq!(
+        cdn::sticker(Snowflake(5), StickerFormatType::Lottie).url(),
+        "https://cdn.discordapp.com/stickers/5.json"
+    );
+    assert_eq!(
+        cdn::sticker(Snowflake(5), StickerFormatType::Apng).url(),
+        "https://cdn.discordapp.com/stickers/5.png"
+    );
+    assert_eq!(
+        cdn::sticker(Snowflake(5), StickerFormatType::Gif).url(),
+        "https://media.discordapp.net/stickers/5.gif"
+    );
+}
@@ -0,0 +1,113 @@
+use discord_rs_model::interaction::{
+    commands_differ, ApplicationCommand, ApplicationCommandOption, ApplicationCommandOptionType,
+    ApplicationCommandType, CreateApplicationCommand,
+};
+use serde_json::json;
+
+fn declared() -> Vec<CreateApplicationCommand> {
+    vec![
+        CreateApplicationCommand::chat_input("roll", "Roll a die").option(
+            ApplicationCommandOption::new(ApplicationCommandOptionType::Integer, "sides", "Number of sides")
+                .required(true)
+                .choice("d6", 6)
+                .choice("d20", 20),
+        ),
+        CreateApplicationCommand::user("Inspect"),
+    ]
+}
+
+fn registered() -> Vec<ApplicationCommand> {
+    serde_json::from_value(json!([
+        {
+            "id": "2", "application_id": "9", "version": "1", "type": 2,
+            "name": "Inspect", "description": "",
+            "name_localizations": {}, "description_localizations": {},
+            "default_member_permissions": null, "dm_permission": true, "nsfw": false
+        },
+        {
+            "id": "1", "application_id": "9", "version": "1", "type": 1,
+            "name": "roll", "description": "Roll a die",
+            "name_localizations": null, "description_localizations": null,
+            "default_member_permissions": null, "dm_permission": true, "nsfw": false,
+            "options": [{
+                "type": 4, "name": "sides", "description": "Number of sides", "required": true,
+                "name_localizations": {},
+                "choices": [{ "name": "d6", "value": 6 }, { "name": "d20", "value": 20 }]
+            }]
+        }
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn test_create_command_serializes_only_set_fields() {
+    let body = serde_json::to_value(&declared()[0]).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "type": 1,
+            "name": "roll",
+            "description": "Roll a die",
+            "default_member_permissions": null,
+            "nsfw": false,
+            "options": [{
+                "type": 4,
+                "name": "sides",
+                "description": "Number of sides",
+                "required": true,
+                "choices": [{ "name": "d6", "value": 6 }, { "name": "d20", "value": 20 }]
+            }]
+        })
+    );
+}
+
+#[test]
+fn test_registered_commands_default_to_chat_input() {
+    let command: ApplicationCommand = serde_json::from_value(json!({
+        "id": "1", "application_id": "9", "version": "1", "name": "ping", "description": "Ping"
+    }))
+    .unwrap();
+    assert_eq!(command.kind, ApplicationCommandType::ChatInput);
+    assert!(command.options.is_empty());
+}
+
+#[test]
+fn test_identical_commands_in_any_order_do_not_differ() {
+    assert!(!commands_differ(&declared(), &registered()));
+}
+
+#[test]
+fn test_number_choices_compare_numerically() {
+    let declared = vec![CreateApplicationCommand::chat_input("scale", "Scale").option(
+        ApplicationCommandOption::new(ApplicationCommandOptionType::Number, "factor", "Factor").choice("double", 2),
+    )];
+    let registered = |value: serde_json::Value| -> Vec<ApplicationCommand> {
+        serde_json::from_value(json!([{
+            "id": "3", "application_id": "9", "version": "1", "type": 1,
+            "name": "scale", "description": "Scale",
+            "options": [{
+                "type": 10, "name": "factor", "description": "Factor",
+                "choices": [{ "name": "double", "value": value }]
+            }]
+        }]))
+        .unwrap()
+    };
+
+    assert!(!commands_differ(&declared, &registered(json!(2.0))));
+    assert!(commands_differ(&declared, &registered(json!(2.5))));
+    assert!(commands_differ(&declared, &registered(json!("2"))));
+}
+
+#[test]
+fn test_changed_added_or_removed_commands_differ() {
+    let mut changed = declared();
+    changed[0] = changed[0].clone().default_member_permissions("0");
+    assert!(commands_differ(&changed, &registered()));
+
+    let mut added = declared();
+    added.push(CreateApplicationCommand::message("Quote"));
+    assert!(commands_differ(&added, &registered()));
+
+    assert!(commands_differ(&declared()[..1], &registered()));
+    assert!(commands_differ(&[], &registered()));
+}
@@ -0,0 +1,37 @@
+use discord_rs_core::DiscordError;
+use discord_rs_model::image::ImageData;
+
+#[test]
+fn test_detects_content_type_from_signature() {
+    let png = ImageData::new(b"\x89PNG\r\n\x1a\n....".to_vec()).unwrap();
+    assert_eq!(png.content_type(), "image/png");
+    assert_eq!(ImageData::new(b"GIF89a..".to_vec()).unwrap().content_type(), "image/gif");
+    assert_eq!(ImageData::new(b"\xff\xd8\xff\xe0".to_vec()).unwrap().content_type(), "image/jpeg");
+    assert_eq!(ImageData::new(b"RIFF\0\0\0\0WEBPVP8 ".to_vec()).unwrap().content_type(), "image/webp");
+
+    assert!(matches!(ImageData::new(b"hello".to_vec()), Err(DiscordError::Validation(_))));
+}
+
+#[test]
+fn test_encodes_as_base64_data_uri() {
+    let image = ImageData::with_content_type("image/png", b"hi!".to_vec());
+    assert_eq!(image.to_data_uri(), "data:image/png;base64,aGkh");
+}
+
+#[test]
+fn test_image_fields_take_image_data() {
+    use discord_rs_model::role::EditRole;
+    use discord_rs_model::webhook::{CreateWebhook, ModifyWebhook};
+    use serde_json::json;
+
+    let image = ImageData::with_content_type("image/png", b"hi!".to_vec());
+
+    let create = serde_json::to_value(CreateWebhook::new("hook").avatar(&image)).unwrap();
+    assert_eq!(create["avatar"], "data:image/png;base64,aGkh");
+
+    let role = serde_json::to_value(EditRole::new().icon(&image)).unwrap();
+    assert_eq!(role, json!({ "icon": "data:image/png;base64,aGkh" }));
+
+    let cleared = serde_json::to_value(ModifyWebhook::new().avatar(None)).unwrap();
+    assert_eq!(cleared, json!({ "avatar": null }));
+}
@@ -0,0 +1,53 @@
+use discord_rs_model::reaction::{Reaction, ReactionType};
+use discord_rs_model::Snowflake;
+use serde_json::json;
+
+#[test]
+fn test_parses_unicode_custom_and_mentions() {
+    assert_eq!("🔥".parse::<ReactionType>().unwrap(), ReactionType::unicode("🔥"));
+    assert_eq!(
+        "blob:123".parse::<ReactionType>().unwrap(),
+        ReactionType::custom("blob", Snowflake(123))
+    );
+    assert_eq!(
+        "<a:dance:456>".parse::<ReactionType>().unwrap(),
+        ReactionType::Custom {
+            id: Snowflake(456),
+            name: Some("dance".to_string()),
+            animated: true
+        }
+    );
+    assert_eq!(
+        "<:blob:123>".parse::<ReactionType>().unwrap(),
+        ReactionType::custom("blob", Snowflake(123))
+    );
+}
+
+#[test]
+fn test_route_component_and_display() {
+    let custom = ReactionType::custom("blob", Snowflake(123));
+    assert_eq!(custom.as_route_component(), "blob:123");
+    assert_eq!(custom.to_string(), "<:blob:123>");
+    assert_eq!(ReactionType::from('👍').as_route_component(), "👍");
+}
+
+#[test]
+fn test_reactions_convert_to_reaction_types() {
+    let reaction: Reaction = serde_json::from_value(json!({
+        "count": 3,
+        "count_details": { "burst": 1, "normal": 2 },
+        "me": true,
+        "me_burst": false,
+        "emoji": { "id": "123", "name": "blob" },
+        "burst_colors": ["#ff0000"]
+    }))
+    .unwrap();
+
+    assert_eq!(reaction.count_details.burst, 1);
+    assert_eq!(ReactionType::from(&reaction.emoji), ReactionType::custom("blob", Snowflake(123)));
+
+    // Older payloads without burst fields still parse.
+    let plain: Reaction =
+        serde_json::from_value(json!({ "count": 1, "me": false, "emoji": { "id": null, "name": "🔥" } })).unwrap();
+    assert_eq!(ReactionType::from(&plain.emoji), ReactionType::unicode("🔥"));
+}
@@ -20,7 +20,7 @@ fn test_user_deserialization() {
     assert_eq!(user.id, Snowflake(80351110224678912));
     assert_eq!(user.username, "Nelly");
     assert_eq!(user.discriminator, "1337");
-    assert_eq!(user.bot, false); // default
+    assert!(!user.bot); // default
 }
 
 #[test]
@@ -29,3 +29,78 @@ fn test_snowflake_serialization() {
     let json = serde_json::to_string(&id).unwrap();
     assert_eq!(json, "\"123456789\"");
 }
+
+#[test]
+fn test_modify_guild_skips_unset_fields() {
+    use discord_rs_model::guild::ModifyGuild;
+
+    let modify = ModifyGuild {
+        name: Some("Renamed".to_string()),
+        afk_timeout: Some(300),
+        ..Default::default()
+    };
+    let value = serde_json::to_value(&modify).unwrap();
+    assert_eq!(value, json!({ "name": "Renamed", "afk_timeout": 300 }));
+}
+
+#[test]
+fn test_modify_guild_clears_nullable_fields() {
+    use discord_rs_model::guild::ModifyGuild;
+
+    let modify = ModifyGuild {
+        icon: Some(None),
+        afk_channel_id: Some(None),
+        system_channel_id: Some(Some(Snowflake(5))),
+        ..Default::default()
+    };
+    let value = serde_json::to_value(&modify).unwrap();
+    assert_eq!(
+        value,
+        json!({ "icon": null, "afk_channel_id": null, "system_channel_id": "5" })
+    );
+}
+
+#[test]
+fn test_modify_member_serializes_explicit_nulls() {
+    use discord_rs_model::member::ModifyGuildMember;
+
+    let modify = ModifyGuildMember::new().remove_timeout().disconnect().mute(true);
+    let value = serde_json::to_value(&modify).unwrap();
+    assert_eq!(
+        value,
+        json!({ "mute": true, "channel_id": null, "communication_disabled_until": null })
+    );
+}
+
+#[test]
+fn test_archived_thread_pagination_cursor() {
+    use discord_rs_model::channel::{ArchivedThreadKind, ListArchivedThreads, ThreadList};
+
+    let page: ThreadList = serde_json::from_value(json!({
+        "threads": [{
+            "id": "41771983423143937",
+            "type": 11,
+            "thread_metadata": {
+                "archived": true,
+                "auto_archive_duration": 1440,
+                "archive_timestamp": "2024-01-02T03:04:05.000000+00:00",
+                "locked": false
+            },
+            "applied_tags": ["1"]
+        }],
+        "members": [],
+        "has_more": true
+    }))
+    .unwrap();
+
+    let query = ListArchivedThreads { before: None, limit: Some(50) };
+    let next = query.next_page(ArchivedThreadKind::Public, &page).unwrap();
+    assert_eq!(next.before.as_deref(), Some("2024-01-02T03:04:05.000000+00:00"));
+    assert_eq!(next.limit, Some(50));
+
+    let joined = query.next_page(ArchivedThreadKind::JoinedPrivate, &page).unwrap();
+    assert_eq!(joined.before.as_deref(), Some("41771983423143937"));
+
+    let last_page = ThreadList { has_more: false, ..page };
+    assert!(query.next_page(ArchivedThreadKind::Public, &last_page).is_none());
+}
@@ -0,0 +1,89 @@
+//! Minimal JSON payloads that deserialize into the model types, for use as
+//! canned responses or as events fed to handlers.
+
+use discord_rs_core::Snowflake;
+use serde_json::{json, Value};
+
+pub fn user(id: Snowflake, username: &str) -> Value {
+    json!({
+        "id": id,
+        "username": username,
+        "discriminator": "0",
+        "global_name": null,
+        "avatar": null,
+    })
+}
+
+pub fn message(id: Snowflake, channel_id: Snowflake, content: &str) -> Value {
+    json!({
+        "id": id,
+        "channel_id": channel_id,
+        "author": user(Snowflake(1), "mock"),
+        "content": content,
+        "timestamp": "2024-01-01T00:00:00.000000+00:00",
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "type": 0,
+    })
+}
+
+/// A slash command interaction from `user_id` in `channel_id`.
+pub fn interaction(id: Snowflake, channel_id: Snowflake, user_id: Snowflake, token: &str) -> Value {
+    json!({
+        "id": id,
+        "application_id": crate::MOCK_APPLICATION_ID,
+        "type": 2,
+        "channel_id": channel_id,
+        "user": user(user_id, "user"),
+        "token": token,
+        "version": 1,
+    })
+}
+
+pub fn role(id: Snowflake, name: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "color": 0,
+        "hoist": false,
+        "icon": null,
+        "unicode_emoji": null,
+        "position": 1,
+        "permissions": "0",
+        "managed": false,
+        "mentionable": false,
+    })
+}
+
+/// A guild owned by user 1 whose only role is `@everyone`.
+pub fn guild(id: Snowflake, name: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "icon": null,
+        "splash": null,
+        "discovery_splash": null,
+        "owner_id": Snowflake(1),
+        "afk_channel_id": null,
+        "afk_timeout": 300,
+        "verification_level": 0,
+        "default_message_notifications": 0,
+        "explicit_content_filter": 0,
+        "roles": [role(id, "@everyone")],
+        "emojis": [],
+        "features": [],
+        "mfa_level": 0,
+        "application_id": null,
+        "system_channel_id": null,
+        "system_channel_flags": 0,
+        "rules_channel_id": null,
+        "vanity_url_code": null,
+        "description": null,
+        "banner": null,
+        "premium_tier": 0,
+        "preferred_locale": "en-US",
+        "public_updates_channel_id": null,
+        "nsfw_level": 0,
+    })
+}
@@ -0,0 +1,49 @@
+//! Test support for bots built on discord_rs.
+//!
+//! `MockHttp` stands in for the REST client behind `Context::http`, so
+//! handlers can be run without talking to Discord:
+//!
+//! ```
+//! # async fn run() -> discord_rs_core::Result<()> {
+//! use discord_rs_core::traits::{HttpExt, HttpMethod, HttpRequest};
+//! use discord_rs_testing::{mock_context, MockHttp, MockResponse};
+//! use serde_json::json;
+//! use std::sync::Arc;
+//!
+//! let http = Arc::new(MockHttp::new());
+//! http.on(HttpMethod::Get, "/users/*", json!({ "id": "1", "username": "a", "discriminator": "0" }));
+//! http.on(HttpMethod::Delete, "/channels/*", MockResponse::api_error(404, 10003, "Unknown Channel"));
+//!
+//! let ctx = mock_context(http.clone());
+//! ctx.http.send_empty(HttpRequest::get("/users/1")).await?;
+//! http.assert_called(HttpMethod::Get, "/users/1");
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod fixtures;
+pub mod mock;
+
+pub use mock::{MockHttp, MockResponse};
+
+use discord_rs_cache::Cache;
+use discord_rs_core::{Config, Context, Snowflake};
+use discord_rs_model::Event;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Application id set on the config of `mock_context`.
+pub const MOCK_APPLICATION_ID: Snowflake = Snowflake(1);
+
+/// Context backed by `http`, an empty cache and a collector broadcaster,
+/// with `application_id` set to `MOCK_APPLICATION_ID`.
+pub fn mock_context(http: Arc<MockHttp>) -> Context {
+    let mut config = Config::new("mock-token");
+    config.application_id = Some(MOCK_APPLICATION_ID);
+    mock_context_with_config(Arc::new(config), http)
+}
+
+pub fn mock_context_with_config(config: Arc<Config>, http: Arc<MockHttp>) -> Context {
+    let (broadcaster, _) = broadcast::channel::<Event>(100);
+    Context::new(config, http, Arc::new(Cache::new()), Arc::new(broadcaster))
+}
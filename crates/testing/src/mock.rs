@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use discord_rs_core::traits::{Http, HttpMethod, HttpRequest};
+use discord_rs_core::{ApiError, DiscordError, JsonErrorCode, Result};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// What `MockHttp` answers a matching request with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockResponse {
+    Json(Value),
+    /// A 204; `send` returns `Value::Null`.
+    NoContent,
+    /// An error response, surfaced as `DiscordError::Api`.
+    Error {
+        status: u16,
+        code: u32,
+        message: String,
+    },
+    /// A 429 the client gave up on, surfaced as `DiscordError::RateLimit`.
+    RateLimited,
+    /// A failure without a response, surfaced as `DiscordError::Http`.
+    Transport(String),
+}
+
+impl MockResponse {
+    pub fn json(value: impl Into<Value>) -> Self {
+        MockResponse::Json(value.into())
+    }
+
+    pub fn api_error(status: u16, code: u32, message: impl Into<String>) -> Self {
+        MockResponse::Error {
+            status,
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn into_result(self) -> Result<Value> {
+        match self {
+            MockResponse::Json(value) => Ok(value),
+            MockResponse::NoContent => Ok(Value::Null),
+            MockResponse::Error { status, code, message } => Err(DiscordError::Api(ApiError {
+                status,
+                code: JsonErrorCode::from(code),
+                message,
+                errors: Vec::new(),
+            })),
+            MockResponse::RateLimited => Err(DiscordError::RateLimit),
+            MockResponse::Transport(message) => Err(DiscordError::Http(message)),
+        }
+    }
+}
+
+impl From<Value> for MockResponse {
+    fn from(value: Value) -> Self {
+        MockResponse::Json(value)
+    }
+}
+
+#[derive(Debug)]
+struct Stub {
+    method: HttpMethod,
+    pattern: String,
+    /// Answered in order; the last one keeps being returned.
+    responses: VecDeque<MockResponse>,
+}
+
+/// `Http` implementation that records every request and answers from
+/// registered responses instead of the network.
+///
+/// Routes are matched by method and a path pattern in which `*` stands for
+/// one segment, e.g. `/channels/*/messages`. The query string is not part of
+/// the match. Requests without a registered response fail with
+/// `DiscordError::Http`.
+#[derive(Debug, Default)]
+pub struct MockHttp {
+    stubs: Mutex<Vec<Stub>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl MockHttp {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a response for `method` on `pattern`. Registering the same
+    /// route again queues the responses: each is returned once, in order,
+    /// and the last one for every request after that. When several patterns
+    /// match, the first registered wins.
+    pub fn on(&self, method: HttpMethod, pattern: &str, response: impl Into<MockResponse>) -> &Self {
+        let response = response.into();
+        let mut stubs = self.stubs.lock().unwrap();
+        match stubs.iter_mut().find(|s| s.method == method && s.pattern == pattern) {
+            Some(stub) => stub.responses.push_back(response),
+            None => stubs.push(Stub {
+                method,
+                pattern: pattern.to_string(),
+                responses: VecDeque::from([response]),
+            }),
+        }
+        self
+    }
+
+    /// Every request sent so far, oldest first.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn requests_to(&self, method: HttpMethod, pattern: &str) -> Vec<HttpRequest> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.method == method && path_matches(pattern, &r.path))
+            .cloned()
+            .collect()
+    }
+
+    pub fn last_request(&self) -> Option<HttpRequest> {
+        self.requests.lock().unwrap().last().cloned()
+    }
+
+    /// Forgets the recorded requests; registered responses stay.
+    pub fn clear_requests(&self) {
+        self.requests.lock().unwrap().clear();
+    }
+
+    /// Panics unless a matching request was sent; returns the latest one.
+    #[track_caller]
+    pub fn assert_called(&self, method: HttpMethod, pattern: &str) -> HttpRequest {
+        match self.requests_to(method, pattern).pop() {
+            Some(request) => request,
+            None => panic!(
+                "expected a request to {} {}, got:\n{}",
+                method_name(method),
+                pattern,
+                self.describe_requests()
+            ),
+        }
+    }
+
+    #[track_caller]
+    pub fn assert_called_times(&self, method: HttpMethod, pattern: &str, times: usize) {
+        let count = self.requests_to(method, pattern).len();
+        assert_eq!(
+            count,
+            times,
+            "expected {} request(s) to {} {}, got:\n{}",
+            times,
+            method_name(method),
+            pattern,
+            self.describe_requests()
+        );
+    }
+
+    #[track_caller]
+    pub fn assert_not_called(&self, method: HttpMethod, pattern: &str) {
+        self.assert_called_times(method, pattern, 0);
+    }
+
+    fn respond(&self, request: &HttpRequest) -> Result<Value> {
+        let mut stubs = self.stubs.lock().unwrap();
+        let stub = stubs
+            .iter_mut()
+            .find(|s| s.method == request.method && path_matches(&s.pattern, &request.path));
+
+        let Some(stub) = stub else {
+            return Err(DiscordError::Http(format!(
+                "MockHttp: no response registered for {} {}",
+                method_name(request.method),
+                request.path
+            )));
+        };
+        let response = if stub.responses.len() > 1 {
+            stub.responses.pop_front()
+        } else {
+            stub.responses.front().cloned()
+        };
+        response.expect("stubs always hold a response").into_result()
+    }
+
+    fn describe_requests(&self) -> String {
+        let requests = self.requests.lock().unwrap();
+        if requests.is_empty() {
+            return "  (no requests)".to_string();
+        }
+        requests
+            .iter()
+            .map(|r| format!("  {} {}", method_name(r.method), r.path))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[async_trait]
+impl Http for MockHttp {
+    async fn send(&self, request: HttpRequest) -> Result<Value> {
+        let result = self.respond(&request);
+        self.requests.lock().unwrap().push(request);
+        result
+    }
+}
+
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_end_matches('/').split('/');
+    let path = path.trim_end_matches('/').split('/');
+    pattern.clone().count() == path.clone().count()
+        && pattern.zip(path).all(|(p, s)| p == "*" || p == s)
+}
+
+fn method_name(method: HttpMethod) -> &'static str {
+    match method {
+        HttpMethod::Get => "GET",
+        HttpMethod::Post => "POST",
+        HttpMethod::Put => "PUT",
+        HttpMethod::Patch => "PATCH",
+        HttpMethod::Delete => "DELETE",
+    }
+}
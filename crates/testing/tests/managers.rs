@@ -0,0 +1,182 @@
+use discord_rs_cache::ContextCacheExt;
+use discord_rs_core::traits::HttpMethod;
+use discord_rs_core::Snowflake;
+use discord_rs_model::member::ModifyGuildMember;
+use discord_rs_model::role::{EditRole, ModifyRolePosition};
+use discord_rs_model::Guild;
+use discord_rs_model::Member;
+use discord_rs_testing::{fixtures, mock_context, MockHttp, MockResponse};
+use serde_json::json;
+use std::sync::Arc;
+
+const GUILD: Snowflake = Snowflake(100);
+
+fn member(user_id: Snowflake) -> serde_json::Value {
+    json!({
+        "user": fixtures::user(user_id, "member"),
+        "roles": [],
+        "joined_at": "2024-01-01T00:00:00.000000+00:00",
+        "deaf": false,
+        "mute": false,
+    })
+}
+
+#[tokio::test]
+async fn test_moderation_actions_send_audit_log_reasons() {
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Put, "/guilds/*/members/*/roles/*", MockResponse::NoContent)
+        .on(HttpMethod::Delete, "/guilds/*/members/*", MockResponse::NoContent)
+        .on(HttpMethod::Put, "/guilds/*/bans/*", MockResponse::NoContent)
+        .on(HttpMethod::Delete, "/guilds/*/bans/*", MockResponse::NoContent)
+        .on(HttpMethod::Patch, "/guilds/*/members/*", member(Snowflake(2)));
+    let ctx = mock_context(http.clone());
+    let members = ctx.members(GUILD);
+
+    members.add_role(Snowflake(2), Snowflake(3), Some("verified")).await.unwrap();
+    members.edit(Snowflake(2), ModifyGuildMember::new().mute(true), Some("noise")).await.unwrap();
+    members.kick(Snowflake(2), Some("spam")).await.unwrap();
+    members.ban(Snowflake(4), Some(3600), Some("raid")).await.unwrap();
+    members.unban(Snowflake(4), None).await.unwrap();
+
+    let request = http.assert_called(HttpMethod::Put, "/guilds/100/members/2/roles/3");
+    assert_eq!(request.reason.as_deref(), Some("verified"));
+    let request = http.assert_called(HttpMethod::Patch, "/guilds/100/members/2");
+    assert_eq!(request.reason.as_deref(), Some("noise"));
+    let request = http.assert_called(HttpMethod::Delete, "/guilds/100/members/2");
+    assert_eq!(request.reason.as_deref(), Some("spam"));
+    let request = http.assert_called(HttpMethod::Put, "/guilds/100/bans/4");
+    assert_eq!(request.reason.as_deref(), Some("raid"));
+    assert_eq!(request.body.unwrap()["delete_message_seconds"], 3600);
+    let request = http.assert_called(HttpMethod::Delete, "/guilds/100/bans/4");
+    assert!(request.reason.is_none());
+}
+
+#[tokio::test]
+async fn test_kick_evicts_the_cached_member() {
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Delete, "/guilds/*/members/*", MockResponse::NoContent);
+    let ctx = mock_context(http.clone());
+    let cached: Member = serde_json::from_value(member(Snowflake(2))).unwrap();
+    ctx.cache().update_member(GUILD, cached);
+
+    assert!(ctx.members(GUILD).get(Snowflake(2)).is_some());
+    ctx.members(GUILD).kick(Snowflake(2), None).await.unwrap();
+    assert!(ctx.members(GUILD).get(Snowflake(2)).is_none());
+}
+
+#[tokio::test]
+async fn test_role_changes_update_the_cached_guild() {
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Post, "/guilds/*/roles", fixtures::role(Snowflake(7), "mods"))
+        .on(HttpMethod::Patch, "/guilds/*/roles/*", fixtures::role(Snowflake(7), "admins"))
+        .on(HttpMethod::Delete, "/guilds/*/roles/*", MockResponse::NoContent);
+    let ctx = mock_context(http.clone());
+    let guild: Guild = serde_json::from_value(fixtures::guild(GUILD, "guild")).unwrap();
+    ctx.cache().update_guild(guild);
+    let roles = ctx.roles(GUILD);
+
+    roles.create(EditRole::new().name("mods"), Some("setup")).await.unwrap();
+    assert_eq!(roles.get(Snowflake(7)).unwrap().name, "mods");
+
+    roles.edit(Snowflake(7), EditRole::new().name("admins"), None).await.unwrap();
+    assert_eq!(roles.get(Snowflake(7)).unwrap().name, "admins");
+    assert_eq!(ctx.guilds().get(GUILD).unwrap().roles.len(), 2);
+
+    roles.delete(Snowflake(7), Some("cleanup")).await.unwrap();
+    assert!(roles.get(Snowflake(7)).is_none());
+    assert!(roles.get(GUILD).is_some());
+
+    let request = http.assert_called(HttpMethod::Post, "/guilds/100/roles");
+    assert_eq!(request.reason.as_deref(), Some("setup"));
+    assert_eq!(request.body.unwrap()["name"], "mods");
+    let request = http.assert_called(HttpMethod::Delete, "/guilds/100/roles/7");
+    assert_eq!(request.reason.as_deref(), Some("cleanup"));
+}
+
+#[tokio::test]
+async fn test_fetch_all_roles_refreshes_the_cache() {
+    let http = Arc::new(MockHttp::new());
+    http.on(
+        HttpMethod::Get,
+        "/guilds/*/roles",
+        json!([fixtures::role(GUILD, "@everyone"), fixtures::role(Snowflake(8), "new")]),
+    );
+    let ctx = mock_context(http.clone());
+    let guild: Guild = serde_json::from_value(fixtures::guild(GUILD, "guild")).unwrap();
+    ctx.cache().update_guild(guild);
+
+    let roles = ctx.roles(GUILD).fetch_all().await.unwrap();
+
+    assert_eq!(roles.len(), 2);
+    assert_eq!(ctx.roles(GUILD).get(Snowflake(8)).unwrap().name, "new");
+}
+
+#[tokio::test]
+async fn test_member_role_changes_update_the_cached_member() {
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Put, "/guilds/*/members/*/roles/*", MockResponse::NoContent)
+        .on(HttpMethod::Delete, "/guilds/*/members/*/roles/*", MockResponse::NoContent);
+    let ctx = mock_context(http.clone());
+    let cached: Member = serde_json::from_value(member(Snowflake(2))).unwrap();
+    ctx.cache().update_member(GUILD, cached);
+    let members = ctx.members(GUILD);
+
+    members.add_role(Snowflake(2), Snowflake(3), None).await.unwrap();
+    members.add_role(Snowflake(2), Snowflake(3), None).await.unwrap();
+    assert_eq!(members.get(Snowflake(2)).unwrap().roles, vec![Snowflake(3)]);
+
+    members.remove_role(Snowflake(2), Snowflake(3), None).await.unwrap();
+    assert!(members.get(Snowflake(2)).unwrap().roles.is_empty());
+}
+
+#[tokio::test]
+async fn test_bulk_ban_evicts_banned_members() {
+    let http = Arc::new(MockHttp::new());
+    http.on(
+        HttpMethod::Post,
+        "/guilds/*/bulk-ban",
+        json!({ "banned_users": ["2"], "failed_users": ["3"] }),
+    );
+    let ctx = mock_context(http.clone());
+    for id in [2, 3] {
+        let cached: Member = serde_json::from_value(member(Snowflake(id))).unwrap();
+        ctx.cache().update_member(GUILD, cached);
+    }
+    let members = ctx.members(GUILD);
+
+    let response = members
+        .bulk_ban(&[Snowflake(2), Snowflake(3)], Some(60), Some("raid"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.failed_users, vec![Snowflake(3)]);
+    assert!(members.get(Snowflake(2)).is_none());
+    assert!(members.get(Snowflake(3)).is_some());
+    let request = http.assert_called(HttpMethod::Post, "/guilds/100/bulk-ban");
+    assert_eq!(request.reason.as_deref(), Some("raid"));
+    assert_eq!(request.body.unwrap(), json!({ "user_ids": ["2", "3"], "delete_message_seconds": 60 }));
+    assert!(members.bulk_ban(&[], None, None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_reorder_roles_refreshes_the_cache() {
+    let mut moved = fixtures::role(Snowflake(7), "mods");
+    moved["position"] = json!(3);
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Patch, "/guilds/*/roles", json!([fixtures::role(GUILD, "@everyone"), moved]));
+    let ctx = mock_context(http.clone());
+    let guild: Guild = serde_json::from_value(fixtures::guild(GUILD, "guild")).unwrap();
+    ctx.cache().update_guild(guild);
+
+    let positions = [
+        ModifyRolePosition { id: Snowflake(7), position: Some(3) },
+        ModifyRolePosition { id: Snowflake(8), position: None },
+    ];
+    let roles = ctx.roles(GUILD).reorder(&positions, Some("promote")).await.unwrap();
+
+    assert_eq!(roles.len(), 2);
+    assert_eq!(ctx.roles(GUILD).get(Snowflake(7)).unwrap().position, 3);
+    let request = http.assert_called(HttpMethod::Patch, "/guilds/100/roles");
+    assert_eq!(request.reason.as_deref(), Some("promote"));
+    assert_eq!(request.body.unwrap(), json!([{ "id": "7", "position": 3 }, { "id": "8" }]));
+}
@@ -0,0 +1,141 @@
+use discord_rs_builders::{InteractionReply, InteractionResponseBuilder, MessageBuilder, MessageSend};
+use discord_rs_cache::ContextCacheExt;
+use discord_rs_core::traits::{Http, HttpMethod, HttpRequest};
+use discord_rs_core::{DiscordError, JsonErrorCode, Snowflake};
+use discord_rs_model::interaction::Interaction;
+use discord_rs_testing::{fixtures, mock_context, MockHttp, MockResponse, MOCK_APPLICATION_ID};
+use serde_json::json;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_message_send_posts_to_channel() {
+    let http = Arc::new(MockHttp::new());
+    http.on(
+        HttpMethod::Post,
+        "/channels/*/messages",
+        fixtures::message(Snowflake(10), Snowflake(5), "hello"),
+    );
+    let ctx = mock_context(http.clone());
+
+    let message = Snowflake(5).send(&ctx, MessageBuilder::new().content("hello")).await.unwrap();
+
+    assert_eq!(message.id, Snowflake(10));
+    let request = http.assert_called(HttpMethod::Post, "/channels/5/messages");
+    assert_eq!(request.body.unwrap()["content"], "hello");
+    assert!(request.files.is_empty());
+}
+
+#[tokio::test]
+async fn test_interaction_reply_and_follow_up() {
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Post, "/interactions/*/*/callback", MockResponse::NoContent)
+        .on(
+            HttpMethod::Post,
+            "/webhooks/*/*/messages",
+            fixtures::message(Snowflake(11), Snowflake(5), "more"),
+        );
+    let ctx = mock_context(http.clone());
+    let interaction: Interaction =
+        serde_json::from_value(fixtures::interaction(Snowflake(7), Snowflake(5), Snowflake(3), "tok")).unwrap();
+
+    interaction.reply(&ctx, InteractionResponseBuilder::reply("pong")).await.unwrap();
+    interaction.follow_up(&ctx, MessageBuilder::new().content("more")).await.unwrap();
+
+    let callback = http.assert_called(HttpMethod::Post, "/interactions/7/tok/callback");
+    let body = callback.body.unwrap();
+    assert_eq!(body["type"], 4);
+    assert_eq!(body["data"]["content"], "pong");
+    http.assert_called(
+        HttpMethod::Post,
+        &format!("/webhooks/{}/tok/messages", MOCK_APPLICATION_ID),
+    );
+    assert_eq!(http.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_interaction_reply_uploads_files() {
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Post, "/interactions/*/*/callback", MockResponse::NoContent);
+    let ctx = mock_context(http.clone());
+    let interaction: Interaction =
+        serde_json::from_value(fixtures::interaction(Snowflake(7), Snowflake(5), Snowflake(3), "tok")).unwrap();
+
+    let mut builder = InteractionResponseBuilder::reply("report");
+    builder.add_file("report.txt", b"all good".to_vec());
+    interaction.reply(&ctx, builder).await.unwrap();
+
+    let callback = http.assert_called(HttpMethod::Post, "/interactions/7/tok/callback");
+    assert_eq!(callback.body.unwrap()["data"]["attachments"][0]["filename"], "report.txt");
+    assert_eq!(callback.files.len(), 1);
+    assert_eq!(callback.files[0].data, b"all good");
+}
+
+#[tokio::test]
+async fn test_managers_fetch_through_http_and_fill_the_cache() {
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Get, "/users/3", fixtures::user(Snowflake(3), "alice"))
+        .on(
+            HttpMethod::Get,
+            "/users/*",
+            MockResponse::api_error(404, 10013, "Unknown User"),
+        );
+    let ctx = mock_context(http.clone());
+
+    assert!(ctx.users().get(Snowflake(3)).is_none());
+    let user = ctx.users().fetch(Snowflake(3)).await.unwrap();
+    assert_eq!(user.username, "alice");
+    assert_eq!(ctx.users().get(Snowflake(3)).unwrap().username, "alice");
+
+    let err = ctx.users().fetch(Snowflake(4)).await.unwrap_err();
+    assert_eq!(err.api_code(), Some(JsonErrorCode::UnknownUser));
+    http.assert_called_times(HttpMethod::Get, "/users/*", 2);
+}
+
+#[tokio::test]
+async fn test_member_manager_sends_bodiless_puts() {
+    let http = Arc::new(MockHttp::new());
+    http.on(HttpMethod::Put, "/guilds/*/members/*/roles/*", MockResponse::NoContent);
+    let ctx = mock_context(http.clone());
+
+    ctx.members(Snowflake(1)).add_role(Snowflake(2), Snowflake(3), None).await.unwrap();
+
+    let request = http.assert_called(HttpMethod::Put, "/guilds/1/members/2/roles/3");
+    assert!(request.body.is_none());
+    http.assert_not_called(HttpMethod::Delete, "/guilds/*/members/*/roles/*");
+}
+
+#[tokio::test]
+async fn test_queued_responses_are_returned_in_order() {
+    let http = MockHttp::new();
+    http.on(HttpMethod::Get, "/gateway", MockResponse::RateLimited)
+        .on(HttpMethod::Get, "/gateway", json!({ "url": "wss://a" }));
+
+    assert!(matches!(http.get("/gateway").await, Err(DiscordError::RateLimit)));
+    assert_eq!(http.get("/gateway").await.unwrap()["url"], "wss://a");
+    // The last response sticks.
+    assert_eq!(http.get("/gateway").await.unwrap()["url"], "wss://a");
+}
+
+#[tokio::test]
+async fn test_unregistered_routes_fail_and_are_still_recorded() {
+    let http = MockHttp::new();
+
+    let err = http
+        .send(HttpRequest::delete("/channels/9").reason("cleanup"))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, DiscordError::Http(ref m) if m.contains("DELETE /channels/9")));
+    let request = http.last_request().unwrap();
+    assert_eq!(request.reason.as_deref(), Some("cleanup"));
+
+    http.clear_requests();
+    assert!(http.requests().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "expected a request to POST /channels/*/messages")]
+fn assert_called_panics_with_the_recorded_requests() {
+    let http = MockHttp::new();
+    http.assert_called(HttpMethod::Post, "/channels/*/messages");
+}
@@ -2,12 +2,14 @@ use discord_rs_core::{Config, Intents, Result, Context, Snowflake};
 use discord_rs_sharding::ShardManager;
 use discord_rs_http::RestClient;
 use discord_rs_model::{Event, Message, Interaction, gateway::Ready};
-use discord_rs_cache::{Cache, update_cache_from_event, UserManager, GuildManager, ChannelManager};
+use discord_rs_model::interaction::CreateApplicationCommand;
+use discord_rs_cache::{Cache, update_cache_from_event, UserManager, GuildManager, ChannelManager, GuildMemberManager, GuildRoleManager};
 use tokio::sync::{mpsc, broadcast};
-use tracing::{info, error, trace};
+use tracing::{info, error, trace, warn};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 // Type alias for async event handlers
 type Handler<T> = Box<dyn Fn(Context, T) -> Pin<Box<dyn Future<Output = Result<()>> + Send>> + Send + Sync>;
@@ -17,6 +19,7 @@ pub struct Client {
     intents: Intents,
     cache: Arc<Cache>,
     rest: Arc<RestClient>,
+    commands: CommandSync,
     // Handlers
     ready_handlers: Vec<Handler<Ready>>,
     message_create_handlers: Vec<Handler<Box<Message>>>,
@@ -33,6 +36,7 @@ impl Client {
             intents: Intents::empty(),
             cache: Arc::new(Cache::new()),
             rest,
+            commands: CommandSync::default(),
             ready_handlers: Vec::new(),
             message_create_handlers: Vec::new(),
             interaction_create_handlers: Vec::new(),
@@ -48,10 +52,30 @@ impl Client {
         let mut config = (*self.config).clone();
         config.application_id = Some(id);
         self.config = Arc::new(config);
-        // We need to recreate REST client to propagate config? 
-        // Actually RestClient holds Arc<Config>. If we change the pointer in Client, RestClient has the old one.
-        // We must update RestClient too.
-        self.rest = Arc::new(RestClient::new(self.config.clone()).expect("Failed to create REST client"));
+        // RestClient holds its own Arc<Config>, so hand it the new one.
+        self.rest = Arc::new(self.rest.with_config(self.config.clone()).expect("Failed to create REST client"));
+        self
+    }
+
+    /// Uses a custom REST client, e.g. one built with `RestClient::builder`
+    /// that points at a rate limit proxy. The client's config is replaced by
+    /// this `Client`'s.
+    pub fn rest_client(mut self, rest: RestClient) -> Self {
+        self.rest = Arc::new(rest.with_config(self.config.clone()).expect("Failed to create REST client"));
+        self
+    }
+
+    /// Global commands to register on the first READY. Registered commands
+    /// are only overwritten if they differ; ones not listed are removed.
+    pub fn global_commands(mut self, commands: Vec<CreateApplicationCommand>) -> Self {
+        self.commands.global = Some(commands);
+        self
+    }
+
+    /// Like `global_commands`, for the commands of one guild.
+    pub fn guild_commands(mut self, guild_id: Snowflake, commands: Vec<CreateApplicationCommand>) -> Self {
+        self.commands.guilds.retain(|(id, _)| *id != guild_id);
+        self.commands.guilds.push((guild_id, commands));
         self
     }
 
@@ -71,6 +95,14 @@ impl Client {
         ChannelManager::new(self.cache.clone(), self.rest.clone())
     }
 
+    pub fn members(&self, guild_id: Snowflake) -> GuildMemberManager {
+        GuildMemberManager::new(guild_id, self.cache.clone(), self.rest.clone())
+    }
+
+    pub fn roles(&self, guild_id: Snowflake) -> GuildRoleManager {
+        GuildRoleManager::new(guild_id, self.cache.clone(), self.rest.clone())
+    }
+
     // --- Event Registration ---
 
     pub fn on_ready<F, Fut>(&mut self, handler: F) -> &mut Self
@@ -135,6 +167,8 @@ impl Client {
         let ready_handlers = Arc::new(self.ready_handlers);
         let message_create_handlers = Arc::new(self.message_create_handlers);
         let interaction_create_handlers = Arc::new(self.interaction_create_handlers);
+        let commands = Arc::new(self.commands);
+        let commands_synced = Arc::new(AtomicBool::new(false));
 
         while let Some(event) = event_rx.recv().await {
             // PHASE 5: Cache-before-dispatch
@@ -148,6 +182,28 @@ impl Client {
             
             match event {
                 Event::Ready(ready) => {
+                    // Every shard sends READY, and again on reconnect; syncing once is enough.
+                    // The flag is cleared again if the sync can't start or fails, so the next READY retries.
+                    if !commands.is_empty() && !commands_synced.swap(true, Ordering::Relaxed) {
+                        let application_id = config.application_id.or(ready.application.as_ref().map(|a| a.id));
+                        match application_id {
+                            Some(application_id) => {
+                                let commands = commands.clone();
+                                let rest = rest.clone();
+                                let synced = commands_synced.clone();
+                                tokio::spawn(async move {
+                                    if commands.sync(rest, application_id).await.is_err() {
+                                        warn!("Application command sync failed, retrying on the next READY");
+                                        synced.store(false, Ordering::Relaxed);
+                                    }
+                                });
+                            }
+                            None => {
+                                warn!("Not syncing application commands: application id unknown");
+                                commands_synced.store(false, Ordering::Relaxed);
+                            }
+                        }
+                    }
                     let handlers = ready_handlers.clone();
                     tokio::spawn(async move {
                         for handler in handlers.iter() {
@@ -186,3 +242,44 @@ impl Client {
         Ok(())
     }
 }
+
+/// Commands declared on the `Client`, synced on the first READY.
+#[derive(Default)]
+struct CommandSync {
+    global: Option<Vec<CreateApplicationCommand>>,
+    guilds: Vec<(Snowflake, Vec<CreateApplicationCommand>)>,
+}
+
+impl CommandSync {
+    fn is_empty(&self) -> bool {
+        self.global.is_none() && self.guilds.is_empty()
+    }
+
+    /// Syncs every command set, even after a failure; returns the first error.
+    async fn sync(self: Arc<Self>, rest: Arc<RestClient>, application_id: Snowflake) -> Result<()> {
+        let mut result = Ok(());
+        if let Some(commands) = &self.global {
+            match rest.sync_global_application_commands(application_id, commands).await {
+                Ok(true) => info!("Registered {} global commands", commands.len()),
+                Ok(false) => info!("Global commands are up to date"),
+                Err(e) => {
+                    error!("Failed to sync global commands: {}", e);
+                    result = Err(e);
+                }
+            }
+        }
+        for (guild_id, commands) in &self.guilds {
+            match rest.sync_guild_application_commands(application_id, *guild_id, commands).await {
+                Ok(true) => info!("Registered {} commands in guild {}", commands.len(), guild_id),
+                Ok(false) => info!("Commands in guild {} are up to date", guild_id),
+                Err(e) => {
+                    error!("Failed to sync commands in guild {}: {}", guild_id, e);
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
@@ -10,6 +10,7 @@ use tokio::time::timeout;
 
 // Collector Event wrapper
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum CollectorEvent {
     Message(Box<Message>),
     Interaction(Interaction),
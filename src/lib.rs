@@ -7,7 +7,7 @@ pub use collector::{Collector, CollectorBuilder};
 // Ergonomic Re-exports
 pub use discord_rs_core::{Intents, Snowflake, Context};
 pub use discord_rs_model::{User, Message, Guild, Channel, Role, Member, Interaction, Event};
-pub use discord_rs_builders::{MessageBuilder, EmbedBuilder, ActionRowBuilder, ButtonBuilder, SelectMenuBuilder, InteractionResponseBuilder};
+pub use discord_rs_builders::{MessageBuilder, EmbedBuilder, ActionRowBuilder, ButtonBuilder, SelectMenuBuilder, InteractionResponseBuilder, CreateAttachment};
 pub use discord_rs_cache::{Cache, ContextCacheExt};
 
 // Internal crates re-exports for advanced users